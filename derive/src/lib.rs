@@ -1,54 +1,81 @@
 extern crate proc_macro;
 
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use std::env::var;
-use syn::token::Token;
 use syn::{Data, DeriveInput, parse_macro_input, parse_quote};
 
-fn add_trait_bounds(mut generics: syn::Generics) -> syn::Generics {
+// Lifetime of the buffer being decoded, introduced on every decode impl
+fn decode_lifetime() -> syn::Lifetime {
+    syn::Lifetime::new("'__de", Span::call_site())
+}
+
+fn add_encode_bounds(mut generics: syn::Generics) -> syn::Generics {
     for param in &mut generics.params {
         if let syn::GenericParam::Type(ref mut type_param) = *param {
             type_param
                 .bounds
                 .push(parse_quote!(femtoflatbuffers::ComponentEncode));
-            type_param
-                .bounds
-                .push(parse_quote!(femtoflatbuffers::ComponentDecode));
         }
     }
     generics
 }
 
-#[proc_macro_derive(Table)]
+fn add_decode_bounds(mut generics: syn::Generics) -> syn::Generics {
+    let decode_lifetime = decode_lifetime();
+    let mut decode_lifetime_param = syn::LifetimeParam::new(decode_lifetime.clone());
+    for param in &mut generics.params {
+        match param {
+            syn::GenericParam::Type(type_param) => {
+                type_param
+                    .bounds
+                    .push(parse_quote!(femtoflatbuffers::ComponentDecode<#decode_lifetime>));
+            }
+            syn::GenericParam::Lifetime(lifetime_param) => {
+                // Anything borrowed by the decoded value must not outlive the buffer
+                decode_lifetime_param.bounds.push(lifetime_param.lifetime.clone());
+            }
+            syn::GenericParam::Const(_) => {}
+        }
+    }
+    generics.params.insert(0, syn::GenericParam::Lifetime(decode_lifetime_param));
+    generics
+}
+
+#[proc_macro_derive(Table, attributes(femto))]
 pub fn flatbuffers_table_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let attributes = parse_femto_attributes(&input.attrs, AttributePosition::Table);
+    if attributes.transparent {
+        if attributes.encode_only {
+            panic!("A #[femto(transparent)] table is written and read like its field, it can't be #[femto(encode_only)]");
+        }
+        return proc_macro::TokenStream::from(transparent_component_impls(&input));
+    }
 
     let name = input.ident;
 
-    let generics = add_trait_bounds(input.generics);
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let encode_generics = add_encode_bounds(input.generics.clone());
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+    let decode_generics = add_decode_bounds(input.generics.clone());
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+    let table_generics = add_decode_bounds(encode_generics.clone());
+    let (table_impl_generics, _, table_where_clause) = table_generics.split_for_impl();
+    let decode_lifetime = decode_lifetime();
 
     let encode = do_encode_table(&input.data);
     let root_offset_ident = format_ident!("root_offset");
-    let decode = do_decode_table(name.clone(), &input.data, root_offset_ident.clone());
-
-    let expanded = quote! {
-        impl #impl_generics femtoflatbuffers::table::Table for #name #ty_generics #where_clause {
-            fn encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<(), femtoflatbuffers::EncodeError> {
-                encoder.encode_u32(4)?;
-                {
-                  #encode
-                }?;
-                Ok(())
-            }
-
-            fn decode(decoder: &femtoflatbuffers::Decoder) -> Result<Self, femtoflatbuffers::DecodeError> {
-                let root_offset = decoder.decode_u32(0)?;
-                #decode
-            }
+    let root_encode = quote! {
+        fn encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<(), femtoflatbuffers::EncodeError> {
+            encoder.encode_u32(4)?;
+            {
+              #encode
+            }?;
+            Ok(())
         }
-        impl #impl_generics femtoflatbuffers::ComponentEncode for #name #ty_generics #where_clause {
+    };
+    let component_encode = quote! {
+        impl #encode_impl_generics femtoflatbuffers::ComponentEncode for #name #ty_generics #encode_where_clause {
             type WorkingValue = (u32, u32);
             fn value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, table_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
                 let value_offset = encoder.encode_i32(0)?;
@@ -58,6 +85,9 @@ pub fn flatbuffers_table_derive(input: proc_macro::TokenStream) -> proc_macro::T
                 encoder.encode_u16((working_value.1 - working_value.0) as u16)?;
                 Ok(())
             }
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                (vector_start, value_offset)
+            }
             fn post_encode(&self, encoder: &mut femtoflatbuffers::Encoder, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
                 match {
                     #encode
@@ -71,32 +101,59 @@ pub fn flatbuffers_table_derive(input: proc_macro::TokenStream) -> proc_macro::T
                 }
             }
         }
-        impl #impl_generics femtoflatbuffers::ComponentDecode for #name #ty_generics #where_clause {
+    };
+
+    // Fields like slices and iterators can only be written, so there's no decoding a table holding one
+    if attributes.encode_only {
+        let expanded = quote! {
+            impl #encode_impl_generics femtoflatbuffers::table::TableEncode for #name #ty_generics #encode_where_clause {
+                #root_encode
+            }
+            #component_encode
+        };
+        return proc_macro::TokenStream::from(expanded);
+    }
+
+    let decode = do_decode_table(name.clone(), &input.data, root_offset_ident.clone());
+    let expanded = quote! {
+        impl #table_impl_generics femtoflatbuffers::table::Table<#decode_lifetime> for #name #ty_generics #table_where_clause {
+            #root_encode
+
+            fn decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>) -> Result<Self, femtoflatbuffers::DecodeError> {
+                let root_offset = decoder.decode_u32(0)?;
+                #decode
+            }
+        }
+        #component_encode
+        impl #decode_impl_generics femtoflatbuffers::ComponentDecode<#decode_lifetime> for #name #ty_generics #decode_where_clause {
             type WorkingValue = (u32, u16);
             type VectorWorkingValue = Self::WorkingValue;
-            fn vtable_decode(decoder: &femtoflatbuffers::Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), femtoflatbuffers::DecodeError> {
-                let vtable_entry_value = decoder.decode_u16(vtable_entry)?;
+            fn vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), femtoflatbuffers::DecodeError> {
+                let vtable_entry_value = decoder.decode_vtable_entry(vtable_entry)?;
                 Ok(((table_start, vtable_entry_value), vtable_entry+2))
             }
-            fn value_decode(decoder: &femtoflatbuffers::Decoder, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
-                let #root_offset_ident = ((working_value.0 + working_value.1 as u32) as i32 + decoder.decode_i32(working_value.0 + working_value.1 as u32)?) as u32;
+            fn value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
+                // Only an Option can stand in for a missing table
+                if working_value.1 == 0 {
+                    return Err(femtoflatbuffers::DecodeError::InvalidData);
+                }
+                let #root_offset_ident = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
                 #decode
             }
-            fn vector_vtable_decode(decoder: &femtoflatbuffers::Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), femtoflatbuffers::DecodeError> {
-                let vtable_entry_value = decoder.decode_u16(vtable_entry)?;
+            fn vector_vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), femtoflatbuffers::DecodeError> {
+                let vtable_entry_value = decoder.decode_vtable_entry(vtable_entry)?;
                 Ok(((table_start, vtable_entry_value), vtable_entry+2))
             }
-            fn vector_len_decode(decoder: &femtoflatbuffers::Decoder, working_value: &Self::VectorWorkingValue) -> Result<usize, femtoflatbuffers::DecodeError> {
-                let vector_offset = (decoder.decode_i32(working_value.0 + working_value.1 as u32)? + working_value.0 as i32) as u32;
+            fn vector_len_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue) -> Result<usize, femtoflatbuffers::DecodeError> {
+                let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
                 Ok(decoder.decode_u32(vector_offset)? as usize)
             }
-            fn vector_value_decode(decoder: &femtoflatbuffers::Decoder, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError>
+            fn vector_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError>
             where
                 Self: Sized
             {
-                let vector_offset = (decoder.decode_i32(working_value.0 + working_value.1 as u32)? + working_value.0 as i32) as u32;
-                let vector_entry_offset = (vector_offset+4) + (idx*4) as u32;
-                let #root_offset_ident = (vector_entry_offset as i32 + decoder.decode_i32(vector_entry_offset)?) as u32;
+                let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+                let #root_offset_ident = decoder.decode_offset(decoder.vector_element(vector_offset, idx, 4)?)?;
                 #decode
             }
         }
@@ -104,6 +161,143 @@ pub fn flatbuffers_table_derive(input: proc_macro::TokenStream) -> proc_macro::T
     proc_macro::TokenStream::from(expanded)
 }
 
+// The single field of a #[femto(transparent)] newtype
+fn transparent_field(data: &Data) -> (syn::Member, &syn::Type) {
+    if let Data::Struct(ref data) = *data {
+        let mut fields = data.fields.iter();
+        match (fields.next(), fields.next()) {
+            (Some(field), None) => {
+                parse_femto_attributes(&field.attrs, AttributePosition::TransparentField);
+                (field_member(0, field), &field.ty)
+            },
+            _ => panic!("A #[femto(transparent)] type needs exactly one field"),
+        }
+    } else {
+        panic!("Only structs are supported");
+    }
+}
+
+// A transparent newtype is written exactly like its field, so every hook forwards to it
+fn transparent_component_impls(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (member, ty) = transparent_field(&input.data);
+
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let encode_generics = add_encode_bounds(input.generics.clone());
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+    let decode_generics = add_decode_bounds(input.generics.clone());
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+    let decode_lifetime = decode_lifetime();
+    let encode_trait = quote! { <#ty as femtoflatbuffers::ComponentEncode> };
+    let decode_trait = quote! { <#ty as femtoflatbuffers::ComponentDecode<#decode_lifetime>> };
+
+    quote! {
+        impl #encode_impl_generics femtoflatbuffers::ComponentEncode for #name #ty_generics #encode_where_clause {
+            type WorkingValue = #encode_trait::WorkingValue;
+            const VECTOR_ELEMENT: bool = #encode_trait::VECTOR_ELEMENT;
+            fn value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, table_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
+                #encode_trait::value_encode(&self.#member, encoder, table_start)
+            }
+            fn vtable_encode(&self, encoder: &mut femtoflatbuffers::Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
+                #encode_trait::vtable_encode(&self.#member, encoder, vtable_start, working_value)
+            }
+            fn post_encode(&self, encoder: &mut femtoflatbuffers::Encoder, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
+                #encode_trait::post_encode(&self.#member, encoder, working_value)
+            }
+            fn vector_value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, vector_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
+                #encode_trait::vector_value_encode(&self.#member, encoder, vector_start)
+            }
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                #encode_trait::vector_working_value(&self.#member, vector_start, value_offset)
+            }
+            fn vector_element_alignment() -> usize {
+                #encode_trait::vector_element_alignment()
+            }
+            fn vtable_entries() -> u32 {
+                #encode_trait::vtable_entries()
+            }
+            fn vector_vtable_entries() -> u32 {
+                #encode_trait::vector_vtable_entries()
+            }
+            fn vector_type_encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<(), femtoflatbuffers::EncodeError> {
+                #encode_trait::vector_type_encode(&self.#member, encoder)
+            }
+            fn union_value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, table_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
+                #encode_trait::union_value_encode(&self.#member, encoder, table_start)
+            }
+            fn union_post_encode(&self, encoder: &mut femtoflatbuffers::Encoder, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
+                #encode_trait::union_post_encode(&self.#member, encoder, working_value)
+            }
+        }
+        impl #decode_impl_generics femtoflatbuffers::ComponentDecode<#decode_lifetime> for #name #ty_generics #decode_where_clause {
+            type WorkingValue = #decode_trait::WorkingValue;
+            type VectorWorkingValue = #decode_trait::VectorWorkingValue;
+            fn vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), femtoflatbuffers::DecodeError> {
+                #decode_trait::vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {#member: #decode_trait::value_decode(decoder, working_value)?})
+            }
+            fn vector_vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), femtoflatbuffers::DecodeError> {
+                #decode_trait::vector_vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn vector_len_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue) -> Result<usize, femtoflatbuffers::DecodeError> {
+                #decode_trait::vector_len_decode(decoder, working_value)
+            }
+            fn vector_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {#member: #decode_trait::vector_value_decode(decoder, working_value, idx)?})
+            }
+            fn vtable_entries() -> u32 {
+                #decode_trait::vtable_entries()
+            }
+            fn vector_vtable_entries() -> u32 {
+                #decode_trait::vector_vtable_entries()
+            }
+            fn is_absent(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<bool, femtoflatbuffers::DecodeError> {
+                #decode_trait::is_absent(decoder, table_start, vtable_entry)
+            }
+            fn union_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {#member: #decode_trait::union_value_decode(decoder, working_value)?})
+            }
+            fn union_vector_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {#member: #decode_trait::union_vector_value_decode(decoder, working_value, idx)?})
+            }
+        }
+    }
+}
+
+// Inside structs only the layout matters, so a transparent newtype just takes on that of its field
+fn transparent_primitive_impls(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (member, ty) = transparent_field(&input.data);
+
+    let generics = add_primitive_bounds(input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let primitive_trait = quote! { <#ty as femtoflatbuffers::components::PrimitiveComponent> };
+    let component_impls = primitive_component_impls(name, &generics);
+
+    quote! {
+        impl #impl_generics femtoflatbuffers::components::PrimitiveComponent for #name #ty_generics #where_clause {
+            fn alignment() -> usize {
+                #primitive_trait::alignment()
+            }
+            fn size() -> usize {
+                #primitive_trait::size()
+            }
+            fn do_encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<u32, femtoflatbuffers::EncodeError> {
+                #primitive_trait::do_encode(&self.#member, encoder)
+            }
+            fn do_decode(decoder: &femtoflatbuffers::Decoder, offset: u32) -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {#member: #primitive_trait::do_decode(decoder, offset)?})
+            }
+            fn absent_decode() -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {#member: #primitive_trait::absent_decode()?})
+            }
+        }
+        #component_impls
+    }
+}
+
 fn inner_do_table_encode(
     table_start_ident: Ident,
     vtable_start_ident: Ident,
@@ -129,90 +323,661 @@ fn inner_do_table_encode(
     }
 }
 
-fn do_encode_table(data: &Data) -> TokenStream {
+// Tuple structs get their vtable slots by position, unit structs are empty tables
+fn struct_fields(data: &Data) -> &syn::Fields {
     if let Data::Struct(ref data) = *data {
-        match data.fields {
-            syn::Fields::Named(ref fields) => {
-                let mut fields_encode = Vec::new();
-                let mut offsets_encode = Vec::new();
-                let mut post_encodes = Vec::new();
-                let table_start_ident = format_ident!("start");
-                let vtable_start_ident = format_ident!("vtable_start");
-                for field in fields.named.iter() {
-                    let field_name = field.ident.as_ref().unwrap();
-                    let working_value_name = format_ident!("{}_working_value", field_name);
-                    fields_encode.push(quote! {
-                        let #working_value_name = femtoflatbuffers::ComponentEncode::value_encode(&self.#field_name, encoder, #table_start_ident)?;
-                    });
-                    offsets_encode.push(quote! {
-                        femtoflatbuffers::ComponentEncode::vtable_encode(&self.#field_name, encoder, #vtable_start_ident, &#working_value_name)?;
-                    });
-                    post_encodes.push(quote! {
-                        femtoflatbuffers::ComponentEncode::post_encode(&self.#field_name, encoder, &#working_value_name)?;
-                    });
-                }
-                inner_do_table_encode(
-                    table_start_ident,
-                    vtable_start_ident,
-                    &fields_encode,
-                    &offsets_encode,
-                    &post_encodes,
-                )
-            }
-            _ => panic!("Only named fields are supported"),
-        }
+        &data.fields
     } else {
         panic!("Only structs are supported");
     }
 }
 
+fn field_member(index: usize, field: &syn::Field) -> syn::Member {
+    match field.ident {
+        Some(ref ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(index))
+    }
+}
+
+// A name for locals holding the field, `field_0` and so on for positional fields
+fn member_ident(member: &syn::Member) -> Ident {
+    match member {
+        syn::Member::Named(ident) => ident.clone(),
+        syn::Member::Unnamed(index) => format_ident!("field_{}", index.index)
+    }
+}
+
+fn do_encode_table(data: &Data) -> TokenStream {
+    let mut fields_encode = Vec::new();
+    let mut offsets_encode = Vec::new();
+    let mut post_encodes = Vec::new();
+    let table_start_ident = format_ident!("start");
+    let vtable_start_ident = format_ident!("vtable_start");
+    for (index, field) in struct_fields(data).iter().enumerate() {
+        let member = field_member(index, field);
+        let field_name = member_ident(&member);
+        let attributes = table_field_attributes(field, &member);
+        if attributes.skip {
+            continue;
+        }
+        let working_value_name = format_ident!("{}_working_value", field_name);
+        // Fields with a custom codec are written as whatever it converts them to
+        let value = match attributes.with {
+            Some(module) => {
+                let wire_value_name = format_ident!("{}_wire_value", field_name);
+                fields_encode.push(quote! {
+                    let #wire_value_name = #module::encode(&self.#member)?;
+                });
+                quote! { &#wire_value_name }
+            }
+            None if attributes.vector => quote! { &self.#member[..] },
+            None => quote! { &self.#member }
+        };
+        fields_encode.push(quote! {
+            let #working_value_name = femtoflatbuffers::ComponentEncode::value_encode(#value, encoder, #table_start_ident)?;
+        });
+        offsets_encode.push(quote! {
+            femtoflatbuffers::ComponentEncode::vtable_encode(#value, encoder, #vtable_start_ident, &#working_value_name)?;
+        });
+        post_encodes.push(quote! {
+            femtoflatbuffers::ComponentEncode::post_encode(#value, encoder, &#working_value_name)?;
+        });
+    }
+    inner_do_table_encode(
+        table_start_ident,
+        vtable_start_ident,
+        &fields_encode,
+        &offsets_encode,
+        &post_encodes,
+    )
+}
+
 fn do_decode_table(type_name: Ident, data: &Data, table_start_ident: Ident) -> TokenStream {
-    if let Data::Struct(ref data) = *data {
-        match data.fields {
-            syn::Fields::Named(ref fields) => {
-                let mut offset_calcs = Vec::new();
-                let mut struct_populations = Vec::new();
-                let offset_ident = format_ident!("offset");
-                for field in fields.named.iter() {
-                    let field_name = field.ident.as_ref().unwrap();
-                    let field_type_name = &field.ty;
-                    let working_value_ident = format_ident!("{}_working_value", field_name);
-                    offset_calcs.push(quote! {
-                        let (#working_value_ident, #offset_ident) = <#field_type_name as femtoflatbuffers::ComponentDecode>::vtable_decode(&decoder, #table_start_ident, #offset_ident)?;
-                    });
-                    struct_populations.push(quote! {
-                        #field_name: <#field_type_name as femtoflatbuffers::ComponentDecode>::value_decode(&decoder, &#working_value_ident)?
-                    });
-                }
-                quote! {
-                    let vtable_offset = ((#table_start_ident as i32) - decoder.decode_i32(#table_start_ident)?) as u32;
-                    let vtable_size = decoder.decode_u16(vtable_offset)?;
-                    let table_size = decoder.decode_u16(vtable_offset + 2)?;
-                    let #offset_ident = vtable_offset + 4;
-                    #(#offset_calcs)*
-                    let res = #type_name {
-                        #(#struct_populations,)*
-                    };
-                    Ok(res)
+    let mut offset_calcs = Vec::new();
+    let mut struct_populations = Vec::new();
+    let offset_ident = format_ident!("offset");
+    let decode_lifetime = decode_lifetime();
+    for (index, field) in struct_fields(data).iter().enumerate() {
+        let member = field_member(index, field);
+        let field_type_name = &field.ty;
+        let working_value_ident = format_ident!("{}_working_value", member_ident(&member));
+        let attributes = table_field_attributes(field, &member);
+        // Skipped fields have no vtable entry, they're only ever filled in here
+        if attributes.skip {
+            let value = match attributes.default {
+                Some(path) => quote! { #path() },
+                None => quote! { ::core::default::Default::default() }
+            };
+            struct_populations.push(quote! { #member: #value });
+            continue;
+        }
+        match attributes.with {
+            Some(module) => {
+                offset_calcs.push(quote! {
+                    let (#working_value_ident, #offset_ident) = femtoflatbuffers::components::with::vtable_decode(#module::decode, &decoder, #table_start_ident, #offset_ident)?;
+                });
+                struct_populations.push(quote! {
+                    #member: femtoflatbuffers::components::with::value_decode(#module::decode, &decoder, &#working_value_ident)?
+                });
+            }
+            None if attributes.vector => {
+                let syn::Type::Array(array) = field_type_name else { unreachable!() };
+                let (element, len) = (&array.elem, &array.len);
+                offset_calcs.push(quote! {
+                    let (#working_value_ident, #offset_ident) = femtoflatbuffers::components::array_vector::vtable_decode::<#element>(&decoder, #table_start_ident, #offset_ident)?;
+                });
+                struct_populations.push(quote! {
+                    #member: femtoflatbuffers::components::array_vector::value_decode::<#element, #len>(&decoder, &#working_value_ident)?
+                });
+            }
+            None => {
+                offset_calcs.push(quote! {
+                    let (#working_value_ident, #offset_ident) = <#field_type_name as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::vtable_decode(&decoder, #table_start_ident, #offset_ident)?;
+                });
+                struct_populations.push(quote! {
+                    #member: <#field_type_name as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::value_decode(&decoder, &#working_value_ident)?
+                });
+            }
+        }
+    }
+    quote! {
+        let decoder = decoder.nested()?;
+        let vtable_offset = decoder.decode_vtable_start(#table_start_ident)?;
+        let vtable_size = decoder.decode_u16(vtable_offset)?;
+        let table_size = decoder.decode_u16(vtable_offset + 2)?;
+        let decoder = decoder.with_vtable(vtable_offset, vtable_size);
+        let #offset_ident = vtable_offset + 4;
+        #(#offset_calcs)*
+        let res = #type_name {
+            #(#struct_populations,)*
+        };
+        Ok(res)
+    }
+}
+
+fn table_field_attributes(field: &syn::Field, member: &syn::Member) -> FemtoAttributes {
+    let attributes = parse_femto_attributes(&field.attrs, AttributePosition::TableField);
+    let field_name = quote!(#member);
+    if attributes.default.is_some() && !attributes.skip {
+        panic!("#[femto(default)] on field `{}` only applies together with #[femto(skip)]", field_name);
+    }
+    if attributes.skip && attributes.with.is_some() {
+        panic!("Field `{}` can't be both #[femto(skip)] and #[femto(with)]", field_name);
+    }
+    if attributes.vector && (attributes.skip || attributes.with.is_some()) {
+        panic!("Field `{}` can't be #[femto(vector)] together with #[femto(skip)] or #[femto(with)]", field_name);
+    }
+    if attributes.vector && !matches!(field.ty, syn::Type::Array(_)) {
+        panic!("#[femto(vector)] on field `{}` only applies to arrays", field_name);
+    }
+    attributes
+}
+
+fn add_primitive_bounds(mut generics: syn::Generics) -> syn::Generics {
+    for param in &mut generics.params {
+        if let syn::GenericParam::Type(ref mut type_param) = *param {
+            type_param
+                .bounds
+                .push(parse_quote!(femtoflatbuffers::components::PrimitiveComponent));
+        }
+    }
+    generics
+}
+
+// The ComponentEncode/ComponentDecode impls of a PrimitiveComponent, these just forward to the shared implementation
+fn primitive_component_impls(name: &Ident, generics: &syn::Generics) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let decode_lifetime = decode_lifetime();
+    let mut decode_generics = generics.clone();
+    decode_generics.params.insert(0, parse_quote!(#decode_lifetime));
+    let (decode_impl_generics, _, decode_where_clause) = decode_generics.split_for_impl();
+    quote! {
+        impl #impl_generics femtoflatbuffers::ComponentEncode for #name #ty_generics #where_clause {
+            type WorkingValue = (u32, u32);
+            fn value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, table_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
+                femtoflatbuffers::components::primitive::value_encode(self, encoder, table_start)
+            }
+            fn vtable_encode(&self, encoder: &mut femtoflatbuffers::Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
+                femtoflatbuffers::components::primitive::vtable_encode(encoder, working_value)
+            }
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                (vector_start, value_offset)
+            }
+            fn vector_element_alignment() -> usize {
+                <Self as femtoflatbuffers::components::PrimitiveComponent>::alignment()
+            }
+            fn union_value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, table_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
+                femtoflatbuffers::components::primitive::union_value_encode(encoder, table_start)
+            }
+            fn union_post_encode(&self, encoder: &mut femtoflatbuffers::Encoder, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
+                femtoflatbuffers::components::primitive::union_post_encode(self, encoder, working_value)
+            }
+        }
+        impl #decode_impl_generics femtoflatbuffers::ComponentDecode<#decode_lifetime> for #name #ty_generics #decode_where_clause {
+            type WorkingValue = (u32, u16);
+            type VectorWorkingValue = Self::WorkingValue;
+            fn vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::value_decode(decoder, working_value)
+            }
+            fn vector_vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn vector_len_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue) -> Result<usize, femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::vector_len_decode(decoder, working_value)
+            }
+            fn vector_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::vector_value_decode(decoder, working_value, idx)
+            }
+            fn union_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::union_value_decode(decoder, working_value)
+            }
+            fn union_vector_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError> {
+                femtoflatbuffers::components::primitive::union_vector_value_decode(decoder, working_value, idx)
+            }
+        }
+    }
+}
+
+#[proc_macro_derive(Struct, attributes(femto))]
+pub fn flatbuffers_struct_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    if parse_femto_attributes(&input.attrs, AttributePosition::Struct).transparent {
+        return proc_macro::TokenStream::from(transparent_primitive_impls(&input));
+    }
+
+    let name = input.ident;
+
+    let generics = add_primitive_bounds(input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // flatc has no empty structs, a unit struct only makes sense as a table
+    let fields = struct_fields(&input.data);
+    if fields.is_empty() {
+        panic!("A struct needs at least one field, use derive(Table) for an empty message");
+    }
+
+    let mut field_alignments = Vec::new();
+    let mut field_sizes = Vec::new();
+    let mut fields_encode = Vec::new();
+    let mut fields_decode = Vec::new();
+    let mut fields_absent_decode = Vec::new();
+    let mut struct_populations = Vec::new();
+    let mut previous_field_type_name = None;
+    for (index, field) in fields.iter().enumerate() {
+        let member = field_member(index, field);
+        let field_name = member_ident(&member);
+        let field_type_name = &field.ty;
+        // A struct's size is fixed by its schema, so every field has to be on the wire as it is
+        parse_femto_attributes(&field.attrs, AttributePosition::StructField);
+        if let Some(previous_field_type_name) = previous_field_type_name {
+            fields_decode.push(quote! {
+                let field_offset = field_offset + <#previous_field_type_name as femtoflatbuffers::components::PrimitiveComponent>::size();
+            });
+        }
+        previous_field_type_name = Some(field_type_name);
+        field_alignments.push(quote! {
+            .max(<#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::alignment())
+        });
+        field_sizes.push(quote! {
+            let size = size.next_multiple_of(<#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::alignment())
+                + <#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::size();
+        });
+        fields_encode.push(quote! {
+            encoder.pad_to_align(<#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::alignment())?;
+            femtoflatbuffers::components::PrimitiveComponent::do_encode(&self.#member, encoder)?;
+        });
+        fields_decode.push(quote! {
+            let field_offset = field_offset.next_multiple_of(<#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::alignment());
+            let #field_name = <#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::do_decode(decoder, offset + field_offset as u32)?;
+        });
+        fields_absent_decode.push(quote! {
+            #member: <#field_type_name as femtoflatbuffers::components::PrimitiveComponent>::absent_decode()?
+        });
+        struct_populations.push(quote! { #member: #field_name });
+    }
+
+    // Same layout rules as flatc: every field aligned to its own alignment, the struct padded to its largest one
+    let component_impls = primitive_component_impls(&name, &generics);
+    let expanded = quote! {
+        impl #impl_generics femtoflatbuffers::components::PrimitiveComponent for #name #ty_generics #where_clause {
+            fn alignment() -> usize {
+                1usize #(#field_alignments)*
+            }
+            fn size() -> usize {
+                let size = 0usize;
+                #(#field_sizes)*
+                size.next_multiple_of(Self::alignment())
+            }
+            fn do_encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<u32, femtoflatbuffers::EncodeError> {
+                encoder.pad_to_align(Self::alignment())?;
+                let start = encoder.used_bytes();
+                #(#fields_encode)*
+                encoder.pad_to_align(Self::alignment())?;
+                Ok(start)
+            }
+            fn do_decode(decoder: &femtoflatbuffers::Decoder, offset: u32) -> Result<Self, femtoflatbuffers::DecodeError> {
+                let field_offset = 0usize;
+                #(#fields_decode)*
+                Ok(#name {
+                    #(#struct_populations,)*
+                })
+            }
+            // Built field by field so structs of any size can be absent
+            fn absent_decode() -> Result<Self, femtoflatbuffers::DecodeError> {
+                Ok(#name {
+                    #(#fields_absent_decode,)*
+                })
+            }
+        }
+        #component_impls
+    };
+    proc_macro::TokenStream::from(expanded)
+}
+
+// Options given through #[femto(...)] on a type, field or variant
+#[derive(Default)]
+struct FemtoAttributes {
+    transparent: bool,
+    encode_only: bool,
+    with: Option<syn::Path>,
+    skip: bool,
+    default: Option<syn::Path>,
+    unknown: bool,
+    none: bool,
+    id: Option<i128>,
+    vector: bool,
+}
+
+// Where a #[femto(...)] attribute is written, each only takes the options that mean something there
+#[derive(Clone, Copy)]
+enum AttributePosition {
+    Table,
+    TableField,
+    Struct,
+    StructField,
+    TransparentField,
+    Enum,
+    EnumVariant,
+    BitFlags,
+    Union,
+    UnionVariant
+}
+
+impl AttributePosition {
+    fn allowed(self) -> &'static [&'static str] {
+        match self {
+            AttributePosition::Table => &["transparent", "encode_only"],
+            AttributePosition::TableField => &["with", "skip", "default", "vector"],
+            AttributePosition::Struct => &["transparent"],
+            AttributePosition::EnumVariant | AttributePosition::BitFlags => &["unknown"],
+            AttributePosition::UnionVariant => &["none", "unknown", "id"],
+            AttributePosition::StructField | AttributePosition::TransparentField | AttributePosition::Enum | AttributePosition::Union => &[]
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            AttributePosition::Table => "a table",
+            AttributePosition::TableField => "a table field",
+            AttributePosition::Struct => "a struct",
+            AttributePosition::StructField => "a struct field",
+            AttributePosition::TransparentField => "the field of a transparent type",
+            AttributePosition::Enum => "an enum",
+            AttributePosition::EnumVariant => "an enum variant",
+            AttributePosition::BitFlags => "a bit flags type",
+            AttributePosition::Union => "a union",
+            AttributePosition::UnionVariant => "a union variant"
+        }
+    }
+}
+
+const FEMTO_ATTRIBUTES: [&str; 9] = ["transparent", "encode_only", "with", "skip", "default", "unknown", "none", "id", "vector"];
+
+fn parse_femto_attributes(attrs: &[syn::Attribute], position: AttributePosition) -> FemtoAttributes {
+    let mut result = FemtoAttributes::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("femto")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta.path.get_ident().map(|ident| ident.to_string()).unwrap_or_default();
+            if FEMTO_ATTRIBUTES.contains(&key.as_str()) && !position.allowed().contains(&key.as_str()) {
+                return Err(meta.error(format!("#[femto({})] isn't supported on {}", key, position.name())));
+            }
+            if meta.path.is_ident("transparent") {
+                result.transparent = true;
+                Ok(())
+            } else if meta.path.is_ident("encode_only") {
+                result.encode_only = true;
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                result.with = Some(value.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                result.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                result.default = Some(value.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("unknown") {
+                result.unknown = true;
+                Ok(())
+            } else if meta.path.is_ident("none") {
+                result.none = true;
+                Ok(())
+            } else if meta.path.is_ident("id") {
+                let value: syn::LitInt = meta.value()?.parse()?;
+                result.id = Some(value.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("vector") {
+                result.vector = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported femto attribute"))
+            }
+        })
+        .unwrap_or_else(|err| panic!("{}", err));
+    }
+    result
+}
+
+fn enum_repr(attrs: &[syn::Attribute]) -> Ident {
+    let mut repr = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident()
+                && ["u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64"].contains(&ident.to_string().as_str())
+            {
+                repr = Some(ident.clone());
+            }
+            Ok(())
+        });
+    }
+    repr.expect("Enums need an integer #[repr(..)]")
+}
+
+fn enum_discriminant(expr: &syn::Expr) -> i128 {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse().unwrap(),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => -enum_discriminant(expr),
+        _ => panic!("Only integer literal discriminants are supported"),
+    }
+}
+
+#[proc_macro_derive(Enum, attributes(femto))]
+pub fn flatbuffers_enum_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    parse_femto_attributes(&input.attrs, AttributePosition::Enum);
+
+    let name = input.ident;
+    let repr = enum_repr(&input.attrs);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let data = if let Data::Enum(ref data) = input.data {
+        data
+    } else {
+        panic!("Only enum are supported");
+    };
+
+    let mut encode_match_cases = Vec::new();
+    let mut decode_match_cases = Vec::new();
+    let mut unknown_variant = None;
+    let mut discriminant = 0i128;
+    for variant in data.variants.iter() {
+        let variant_ident = &variant.ident;
+        if parse_femto_attributes(&variant.attrs, AttributePosition::EnumVariant).unknown {
+            if unknown_variant.is_some() {
+                panic!("Only one variant can be marked #[femto(unknown)]");
+            }
+            if !matches!(variant.fields, syn::Fields::Unnamed(ref fields) if fields.unnamed.len() == 1) {
+                panic!("The #[femto(unknown)] variant needs exactly one unnamed field holding the raw value");
+            }
+            unknown_variant = Some(variant_ident);
+            continue;
+        }
+        if !variant.fields.is_empty() {
+            panic!("Only unit variants are supported");
+        }
+        if let Some((_, expr)) = &variant.discriminant {
+            discriminant = enum_discriminant(expr);
+        }
+        let value = proc_macro2::Literal::i128_unsuffixed(discriminant);
+        encode_match_cases.push(quote! {
+            #name::#variant_ident => #value
+        });
+        decode_match_cases.push(quote! {
+            #value => Ok(#name::#variant_ident)
+        });
+        discriminant += 1;
+    }
+    if let Some(unknown_variant) = unknown_variant {
+        encode_match_cases.push(quote! {
+            #name::#unknown_variant(value) => *value
+        });
+        decode_match_cases.push(quote! {
+            value => Ok(#name::#unknown_variant(value))
+        });
+    } else {
+        decode_match_cases.push(quote! {
+            _ => Err(femtoflatbuffers::DecodeError::UnknownDiscriminant)
+        });
+    }
+
+    let component_impls = primitive_component_impls(&name, &input.generics);
+    let expanded = quote! {
+        impl #impl_generics femtoflatbuffers::components::PrimitiveComponent for #name #ty_generics #where_clause {
+            fn alignment() -> usize {
+                <#repr as femtoflatbuffers::components::PrimitiveComponent>::alignment()
+            }
+            fn size() -> usize {
+                <#repr as femtoflatbuffers::components::PrimitiveComponent>::size()
+            }
+            fn do_encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<u32, femtoflatbuffers::EncodeError> {
+                let value: #repr = match self {
+                    #(#encode_match_cases,)*
+                };
+                femtoflatbuffers::components::PrimitiveComponent::do_encode(&value, encoder)
+            }
+            fn do_decode(decoder: &femtoflatbuffers::Decoder, offset: u32) -> Result<Self, femtoflatbuffers::DecodeError> {
+                match <#repr as femtoflatbuffers::components::PrimitiveComponent>::do_decode(decoder, offset)? {
+                    #(#decode_match_cases,)*
                 }
             }
-            _ => panic!("Only named fields are supported"),
+        }
+        #component_impls
+    };
+    proc_macro::TokenStream::from(expanded)
+}
+
+// Flags types are anything implementing bitflags::Flags, unknown bits are rejected unless the type is marked #[femto(unknown)]
+// or the decoder is made to keep them with Decoder::with_unknown_flags
+#[proc_macro_derive(BitFlags, attributes(femto))]
+pub fn flatbuffers_bit_flags_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let from_bits = if parse_femto_attributes(&input.attrs, AttributePosition::BitFlags).unknown {
+        quote! {
+            Ok(<Self as femtoflatbuffers::bitflags::Flags>::from_bits_retain(bits))
         }
     } else {
-        panic!("Only structs are supported");
+        quote! {
+            if decoder.keeps_unknown_flags() {
+                Ok(<Self as femtoflatbuffers::bitflags::Flags>::from_bits_retain(bits))
+            } else {
+                <Self as femtoflatbuffers::bitflags::Flags>::from_bits(bits).ok_or(femtoflatbuffers::DecodeError::UnknownFlags)
+            }
+        }
+    };
+
+    let component_impls = primitive_component_impls(&name, &input.generics);
+    let expanded = quote! {
+        impl #impl_generics femtoflatbuffers::components::PrimitiveComponent for #name #ty_generics #where_clause {
+            fn alignment() -> usize {
+                <<Self as femtoflatbuffers::bitflags::Flags>::Bits as femtoflatbuffers::components::PrimitiveComponent>::alignment()
+            }
+            fn size() -> usize {
+                <<Self as femtoflatbuffers::bitflags::Flags>::Bits as femtoflatbuffers::components::PrimitiveComponent>::size()
+            }
+            fn do_encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<u32, femtoflatbuffers::EncodeError> {
+                femtoflatbuffers::components::PrimitiveComponent::do_encode(&femtoflatbuffers::bitflags::Flags::bits(self), encoder)
+            }
+            fn do_decode(decoder: &femtoflatbuffers::Decoder, offset: u32) -> Result<Self, femtoflatbuffers::DecodeError> {
+                let bits = <<Self as femtoflatbuffers::bitflags::Flags>::Bits as femtoflatbuffers::components::PrimitiveComponent>::do_decode(decoder, offset)?;
+                #from_bits
+            }
+        }
+        #component_impls
+    };
+    proc_macro::TokenStream::from(expanded)
+}
+
+enum UnionVariantKind {
+    None,
+    Unknown,
+    Member(u8)
+}
+
+// NONE is always 0, other members count up from the previous id unless given one through #[femto(id = N)] or a discriminant
+fn union_variant_kinds(data: &syn::DataEnum) -> Vec<UnionVariantKind> {
+    let attributes: Vec<_> = data.variants.iter().map(|variant| parse_femto_attributes(&variant.attrs, AttributePosition::UnionVariant)).collect();
+    if attributes.iter().filter(|attributes| attributes.none).count() > 1 {
+        panic!("A union can only have one #[femto(none)] variant");
     }
+    if attributes.iter().filter(|attributes| attributes.unknown).count() > 1 {
+        panic!("A union can only have one #[femto(unknown)] variant");
+    }
+    let none_index = attributes.iter().position(|attributes| attributes.none)
+        .or_else(|| data.variants.iter().zip(attributes.iter()).position(|(variant, attributes)| {
+            variant.fields.is_empty() && attributes.id.or(variant.discriminant.as_ref().map(|(_, expr)| enum_discriminant(expr))) == Some(0)
+        }))
+        .or_else(|| data.variants.first().filter(|variant| variant.fields.is_empty()).map(|_| 0));
+
+    let mut used_ids = vec![];
+    let mut next_id = 1;
+    data.variants.iter().zip(attributes).enumerate().map(|(idx, (variant, attributes))| {
+        let variant_ident = &variant.ident;
+        if attributes.id.is_some() && variant.discriminant.is_some() {
+            panic!("Union variant `{}` has both a discriminant and #[femto(id)]", variant_ident);
+        }
+        let explicit_id = attributes.id.or(variant.discriminant.as_ref().map(|(_, expr)| enum_discriminant(expr)));
+        if Some(idx) == none_index {
+            if !variant.fields.is_empty() {
+                panic!("The none variant `{}` of a union can't hold a value", variant_ident);
+            }
+            if explicit_id.is_some_and(|id| id != 0) {
+                panic!("The none variant `{}` of a union always has id 0", variant_ident);
+            }
+            UnionVariantKind::None
+        } else if attributes.unknown {
+            if variant.fields.len() != 2 || !matches!(variant.fields, syn::Fields::Unnamed(_)) {
+                panic!("The unknown variant `{}` of a union has to be {}(u8, RawTable)", variant_ident, variant_ident);
+            }
+            if explicit_id.is_some() {
+                panic!("The unknown variant `{}` of a union can't have an id", variant_ident);
+            }
+            UnionVariantKind::Unknown
+        } else {
+            match &variant.fields {
+                syn::Fields::Unit => panic!("Union variant `{}` has no value, only the none variant can be a unit variant (see #[femto(none)])", variant_ident),
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {}
+                _ => panic!("Union variant `{}` has to hold exactly one unnamed value", variant_ident)
+            }
+            let id = explicit_id.unwrap_or(next_id);
+            if id == 0 {
+                panic!("Union variant `{}` can't use id 0, it's reserved for NONE", variant_ident);
+            }
+            let id = u8::try_from(id).unwrap_or_else(|_| panic!("Union variant `{}` has id {} which doesn't fit in a u8", variant_ident, id));
+            if used_ids.contains(&id) {
+                panic!("Union variant `{}` reuses id {}", variant_ident, id);
+            }
+            used_ids.push(id);
+            next_id = id as i128 + 1;
+            UnionVariantKind::Member(id)
+        }
+    }).collect()
 }
 
-#[proc_macro_derive(Union)]
+#[proc_macro_derive(Union, attributes(femto))]
 pub fn flatbuffers_union_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    parse_femto_attributes(&input.attrs, AttributePosition::Union);
 
     let name = input.ident;
+    let vis = input.vis;
 
-    let generics = add_trait_bounds(input.generics);
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let encode_generics = add_encode_bounds(input.generics.clone());
+    let (encode_impl_generics, _, encode_where_clause) = encode_generics.split_for_impl();
+    let decode_generics = add_decode_bounds(input.generics.clone());
+    let (decode_impl_generics, decode_ty_generics, decode_where_clause) = decode_generics.split_for_impl();
+    let decode_lifetime = decode_lifetime();
     let expanded = if let Data::Enum(ref data) = input.data {
-        let mut variant_id = 0u8;
         let encode_working_value_enum_ident = format_ident!("EncodeWorkingValue{}", name);
         let decode_working_value_enum_ident = format_ident!("DecodeWorkingValue{}", name);
         let mut encode_working_value_enum_arms = vec![];
@@ -222,31 +987,121 @@ pub fn flatbuffers_union_derive(input: proc_macro::TokenStream) -> proc_macro::T
         let mut post_encode_match_cases = vec![];
         let mut vtable_decode_match_cases = vec![];
         let mut decode_match_cases = vec![];
-        for variant in &data.variants {
+        let mut vector_value_encode_match_cases = vec![];
+        let mut vector_working_value_match_cases = vec![];
+        let mut vector_type_encode_match_cases = vec![];
+        let mut vector_working_value_fields = vec![];
+        let mut vector_vtable_decodes = vec![];
+        let mut vector_decode_match_cases = vec![];
+        let mut vector_variant_types = vec![];
+        let variant_kinds = union_variant_kinds(data);
+        let absent_decode = match data.variants.iter().zip(variant_kinds.iter()).find(|(_, kind)| matches!(kind, UnionVariantKind::None)) {
+            Some((variant, _)) => {
+                let variant_ident = &variant.ident;
+                quote! { Ok(#name::#variant_ident) }
+            }
+            None => quote! { Err(femtoflatbuffers::DecodeError::InvalidData) }
+        };
+        let mut unknown_vtable_decode = quote! {
+            _ => {
+                Err(femtoflatbuffers::DecodeError::InvalidData)
+            }
+        };
+        let mut unknown_vector_decode = unknown_vtable_decode.clone();
+        for (variant, kind) in data.variants.iter().zip(variant_kinds) {
             let variant_ident = variant.ident.clone();
-            if variant_id == 0 {
-                value_encode_match_cases.push(quote! {
-                    #name::#variant_ident => {
-                        Err(femtoflatbuffers::EncodeError::InvalidStructure)
-                    }
-                });
-            } else {
-                if variant.fields.is_empty() {
-                    // Skip for now
-                } else {
+            match kind {
+                UnionVariantKind::None => {
+                    value_encode_match_cases.push(quote! {
+                        #name::#variant_ident => {
+                            Ok(((table_start, 0), #encode_working_value_enum_ident::Absent))
+                        }
+                    });
+                }
+                UnionVariantKind::Unknown => {
+                    // Keeps the discriminant next to the undecoded member, so it can be written back unchanged
+                    let variant_type = &variant.fields.iter().nth(1).unwrap().ty;
+                    let enum_arm_ident = format_ident!("{}Arm", variant_ident);
+                    encode_working_value_enum_arms.push(quote!{
+                        #enum_arm_ident(<#variant_type as femtoflatbuffers::ComponentEncode>::WorkingValue)
+                    });
+                    decode_working_value_enum_arms.push(quote!{
+                        #enum_arm_ident(u8, <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::WorkingValue)
+                    });
+                    value_encode_match_cases.push(quote!{
+                        #name::#variant_ident(which_value, field) => {
+                            let res = encoder.encode_u8(*which_value)?;
+                            let value_res = femtoflatbuffers::ComponentEncode::union_value_encode(field, encoder, table_start)?;
+                            Ok(((table_start, res), #encode_working_value_enum_ident::#enum_arm_ident(value_res)))
+                        }
+                    });
+                    vtable_encode_match_cases.push(quote!{
+                        (#name::#variant_ident(_, field), ((table_start, which_offset), #encode_working_value_enum_ident::#enum_arm_ident(working_value))) => {
+                            encoder.encode_u16((which_offset - table_start) as u16)?;
+                            femtoflatbuffers::ComponentEncode::vtable_encode(field, encoder, vtable_start, working_value)
+                        }
+                    });
+                    post_encode_match_cases.push(quote!{
+                        (#name::#variant_ident(_, field), #encode_working_value_enum_ident::#enum_arm_ident(working_value)) => {
+                            femtoflatbuffers::ComponentEncode::union_post_encode(field, encoder, working_value)?;
+                        }
+                    });
+                    unknown_vtable_decode = quote!{
+                        which_value => {
+                            let (working_value, next_offset) = <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::vtable_decode(decoder, table_start, vtable_entry+2)?;
+                            Ok((#decode_working_value_enum_ident::#enum_arm_ident(which_value, working_value), next_offset))
+                        }
+                    };
+                    decode_match_cases.push(quote!{
+                        #decode_working_value_enum_ident::#enum_arm_ident(which_value, inner_working_value) => {
+                            Ok(#name::#variant_ident(*which_value, <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::union_value_decode(decoder, inner_working_value)?))
+                        }
+                    });
+                    vector_working_value_match_cases.push(quote!{
+                        #name::#variant_ident(_, field) => {
+                            ((vector_start, 0), #encode_working_value_enum_ident::#enum_arm_ident(femtoflatbuffers::ComponentEncode::vector_working_value(field, vector_start, value_offset)))
+                        }
+                    });
+                    vector_value_encode_match_cases.push(quote!{
+                        #name::#variant_ident(_, field) => {
+                            let value_res = femtoflatbuffers::ComponentEncode::union_value_encode(field, encoder, vector_start)?;
+                            Ok(((vector_start, 0), #encode_working_value_enum_ident::#enum_arm_ident(value_res)))
+                        }
+                    });
+                    vector_type_encode_match_cases.push(quote!{
+                        #name::#variant_ident(which_value, _) => {
+                            encoder.encode_u8(*which_value)?;
+                            Ok(())
+                        }
+                    });
+                    let field_index = syn::Index::from(vector_working_value_fields.len() + 1);
+                    vector_variant_types.push(variant_type.clone());
+                    vector_working_value_fields.push(quote!{
+                        <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::VectorWorkingValue
+                    });
+                    vector_vtable_decodes.push(quote!{
+                        <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::vector_vtable_decode(decoder, table_start, vtable_entry+2)?.0
+                    });
+                    unknown_vector_decode = quote!{
+                        which_value => {
+                            Ok(#name::#variant_ident(which_value, <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::union_vector_value_decode(decoder, &working_value.#field_index, idx)?))
+                        }
+                    };
+                }
+                UnionVariantKind::Member(variant_id) => {
                     let variant_field = variant.fields.iter().next().unwrap();
                     let variant_type = &variant_field.ty;
-                    let enum_arm_ident = format_ident!("{}_arm", variant_ident);
+                    let enum_arm_ident = format_ident!("{}Arm", variant_ident);
                     encode_working_value_enum_arms.push(quote!{
                         #enum_arm_ident(<#variant_type as femtoflatbuffers::ComponentEncode>::WorkingValue)
                     });
                     decode_working_value_enum_arms.push(quote!{
-                        #enum_arm_ident(<#variant_type as femtoflatbuffers::ComponentDecode>::WorkingValue)
+                        #enum_arm_ident(<#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::WorkingValue)
                     });
                     value_encode_match_cases.push(quote!{
                         #name::#variant_ident(field, ..) => {
                             let res = encoder.encode_u8(#variant_id)?;
-                            let value_res = femtoflatbuffers::ComponentEncode::value_encode(field, encoder, table_start)?;
+                            let value_res = femtoflatbuffers::ComponentEncode::union_value_encode(field, encoder, table_start)?;
                             Ok(((table_start, res), #encode_working_value_enum_ident::#enum_arm_ident(value_res)))
                         }
                    });
@@ -258,39 +1113,80 @@ pub fn flatbuffers_union_derive(input: proc_macro::TokenStream) -> proc_macro::T
                    });
                     post_encode_match_cases.push(quote!{
                         (#name::#variant_ident(field), #encode_working_value_enum_ident::#enum_arm_ident(working_value)) => {
-                            femtoflatbuffers::ComponentEncode::post_encode(field, encoder, working_value)?;
+                            femtoflatbuffers::ComponentEncode::union_post_encode(field, encoder, working_value)?;
                         }
                     });
                     vtable_decode_match_cases.push(quote!{
                         #variant_id => {
-                            let (working_value, next_offset) = <#variant_type as femtoflatbuffers::ComponentDecode>::vtable_decode(decoder, table_start, vtable_entry+2)?;
+                            let (working_value, next_offset) = <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::vtable_decode(decoder, table_start, vtable_entry+2)?;
                             Ok((#decode_working_value_enum_ident::#enum_arm_ident(working_value), next_offset))
                         }
                     });
                     decode_match_cases.push(quote!{
                         #decode_working_value_enum_ident::#enum_arm_ident(inner_working_value) => {
-                            Ok(#name::#variant_ident(<#variant_type as femtoflatbuffers::ComponentDecode>::value_decode(decoder, inner_working_value)?))
+                            Ok(#name::#variant_ident(<#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::union_value_decode(decoder, inner_working_value)?))
+                        }
+                    });
+                    vector_working_value_match_cases.push(quote!{
+                        #name::#variant_ident(field, ..) => {
+                            ((vector_start, 0), #encode_working_value_enum_ident::#enum_arm_ident(femtoflatbuffers::ComponentEncode::vector_working_value(field, vector_start, value_offset)))
+                        }
+                    });
+                    vector_value_encode_match_cases.push(quote!{
+                        #name::#variant_ident(field, ..) => {
+                            let value_res = femtoflatbuffers::ComponentEncode::union_value_encode(field, encoder, vector_start)?;
+                            Ok(((vector_start, 0), #encode_working_value_enum_ident::#enum_arm_ident(value_res)))
+                        }
+                    });
+                    vector_type_encode_match_cases.push(quote!{
+                        #name::#variant_ident(..) => {
+                            encoder.encode_u8(#variant_id)?;
+                            Ok(())
+                        }
+                    });
+                    // Field 0 is the type vector, every member gets its own view of the value vector
+                    let field_index = syn::Index::from(vector_working_value_fields.len() + 1);
+                    vector_variant_types.push(variant_type.clone());
+                    vector_working_value_fields.push(quote!{
+                        <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::VectorWorkingValue
+                    });
+                    vector_vtable_decodes.push(quote!{
+                        <#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::vector_vtable_decode(decoder, table_start, vtable_entry+2)?.0
+                    });
+                    vector_decode_match_cases.push(quote!{
+                        #variant_id => {
+                            Ok(#name::#variant_ident(<#variant_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::union_vector_value_decode(decoder, &working_value.#field_index, idx)?))
                         }
                     });
                 }
             }
-            variant_id += 1;
         }
+        let decode_vector_working_value_ident = format_ident!("DecodeVectorWorkingValue{}", name);
+        let vector_len_check = vector_variant_types.first().map(|first_type| quote!{
+            if len != <#first_type as femtoflatbuffers::ComponentDecode<#decode_lifetime>>::vector_len_decode(decoder, &working_value.1)? {
+                return Err(femtoflatbuffers::DecodeError::InvalidData);
+            }
+        });
         let expanded = quote! {
-            enum #encode_working_value_enum_ident {
+            #[doc(hidden)]
+            #[allow(clippy::enum_variant_names)]
+            #vis enum #encode_working_value_enum_ident #encode_impl_generics #encode_where_clause {
+                Absent,
                 #(#encode_working_value_enum_arms,)*
             }
-            enum #decode_working_value_enum_ident {
+            #[doc(hidden)]
+            #[allow(clippy::enum_variant_names)]
+            #vis enum #decode_working_value_enum_ident #decode_impl_generics #decode_where_clause {
+                Absent,
                 #(#decode_working_value_enum_arms,)*
             }
-            impl #impl_generics femtoflatbuffers::ComponentEncode for #name #ty_generics #where_clause {
-                type WorkingValue = ((u32, u32), #encode_working_value_enum_ident);
+            #[doc(hidden)]
+            #vis struct #decode_vector_working_value_ident #decode_impl_generics (u32, #(#vector_working_value_fields,)*) #decode_where_clause;
+            impl #encode_impl_generics femtoflatbuffers::ComponentEncode for #name #ty_generics #encode_where_clause {
+                type WorkingValue = ((u32, u32), #encode_working_value_enum_ident #ty_generics);
                 fn value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, table_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
                     match self {
                         #(#value_encode_match_cases)*
-                        _ => {
-                            Err(femtoflatbuffers::EncodeError::InvalidStructure)
-                        }
                     }
                 }
                 fn vtable_encode(&self, encoder: &mut femtoflatbuffers::Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), femtoflatbuffers::EncodeError> {
@@ -310,42 +1206,100 @@ pub fn flatbuffers_union_derive(input: proc_macro::TokenStream) -> proc_macro::T
                     }
                     Ok(())
                 }
+                fn vector_value_encode(&self, encoder: &mut femtoflatbuffers::Encoder, vector_start: u32) -> Result<Self::WorkingValue, femtoflatbuffers::EncodeError> {
+                    match self {
+                        #(#vector_value_encode_match_cases)*
+                        _ => {
+                            Err(femtoflatbuffers::EncodeError::InvalidStructure)
+                        }
+                    }
+                }
+                fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                    match self {
+                        #(#vector_working_value_match_cases)*
+                        _ => {
+                            ((vector_start, 0), #encode_working_value_enum_ident::Absent)
+                        }
+                    }
+                }
+                fn vtable_entries() -> u32 {
+                    2
+                }
+                fn vector_vtable_entries() -> u32 {
+                    2
+                }
+                fn vector_type_encode(&self, encoder: &mut femtoflatbuffers::Encoder) -> Result<(), femtoflatbuffers::EncodeError> {
+                    match self {
+                        #(#vector_type_encode_match_cases)*
+                        _ => {
+                            Err(femtoflatbuffers::EncodeError::InvalidStructure)
+                        }
+                    }
+                }
             }
-            impl #impl_generics femtoflatbuffers::ComponentDecode for #name #ty_generics #where_clause {
-                type WorkingValue = #decode_working_value_enum_ident;
-                type VectorWorkingValue = ();
-                fn vtable_decode(decoder: &femtoflatbuffers::Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), femtoflatbuffers::DecodeError> {
-                    let which_offset = decoder.decode_u16(vtable_entry)?;
+            impl #decode_impl_generics femtoflatbuffers::ComponentDecode<#decode_lifetime> for #name #ty_generics #decode_where_clause {
+                type WorkingValue = #decode_working_value_enum_ident #decode_ty_generics;
+                type VectorWorkingValue = #decode_vector_working_value_ident #decode_ty_generics;
+                fn vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), femtoflatbuffers::DecodeError> {
+                    let which_offset = decoder.decode_vtable_entry(vtable_entry)?;
                     if which_offset != 0 {
                         let which_value = decoder.decode_u8((which_offset as u32) + table_start)?;
                         let (inner_working_value, next_offset) = match which_value {
-                            #(#vtable_decode_match_cases)*
-                            _ => {
-                                Err(femtoflatbuffers::DecodeError::InvalidData)
+                            0 => {
+                                Ok((#decode_working_value_enum_ident::Absent, vtable_entry+4))
                             }
+                            #(#vtable_decode_match_cases)*
+                            #unknown_vtable_decode
                         }?;
                         Ok((inner_working_value, next_offset))
                     }
                     else {
-                        Err(femtoflatbuffers::DecodeError::InvalidData)
+                        Ok((#decode_working_value_enum_ident::Absent, vtable_entry+4))
                     }
                 }
-                fn value_decode(decoder: &femtoflatbuffers::Decoder, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
+                fn value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::WorkingValue) -> Result<Self, femtoflatbuffers::DecodeError> {
                     match working_value {
+                        #decode_working_value_enum_ident::Absent => {
+                            #absent_decode
+                        }
                         #(#decode_match_cases,)*
                         _ => {
                             Err(femtoflatbuffers::DecodeError::InvalidData)
                         }
                     }
                 }
-                fn vector_vtable_decode(decoder: &femtoflatbuffers::Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), femtoflatbuffers::DecodeError> {
-                    Err(femtoflatbuffers::DecodeError::UnsupportedFeature)
+                fn vector_vtable_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), femtoflatbuffers::DecodeError> {
+                    // The type vector comes first, the value vector takes the following vtable entry
+                    let types_offset = decoder.decode_vtable_entry(vtable_entry)?;
+                    if types_offset == 0 || decoder.decode_vtable_entry(vtable_entry+2)? == 0 {
+                        return Err(femtoflatbuffers::DecodeError::InvalidData);
+                    }
+                    let types_vector = decoder.decode_offset(table_start + types_offset as u32)?;
+                    Ok((#decode_vector_working_value_ident(types_vector, #(#vector_vtable_decodes,)*), vtable_entry+4))
                 }
-                fn vector_len_decode(decoder: &femtoflatbuffers::Decoder, working_value: &Self::VectorWorkingValue) -> Result<usize, femtoflatbuffers::DecodeError> {
-                    Err(femtoflatbuffers::DecodeError::UnsupportedFeature)
+                fn vector_len_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue) -> Result<usize, femtoflatbuffers::DecodeError> {
+                    let len = decoder.decode_u32(working_value.0)? as usize;
+                    #vector_len_check
+                    Ok(len)
                 }
-                fn vector_value_decode(decoder: &femtoflatbuffers::Decoder, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError> {
-                    Err(femtoflatbuffers::DecodeError::UnsupportedFeature)
+                fn vector_value_decode(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, femtoflatbuffers::DecodeError> {
+                    match decoder.decode_u8(decoder.vector_element(working_value.0, idx, 1)?)? {
+                        0 => {
+                            Err(femtoflatbuffers::DecodeError::InvalidData)
+                        }
+                        #(#vector_decode_match_cases)*
+                        #unknown_vector_decode
+                    }
+                }
+                fn vtable_entries() -> u32 {
+                    2
+                }
+                fn vector_vtable_entries() -> u32 {
+                    2
+                }
+                fn is_absent(decoder: &femtoflatbuffers::Decoder<#decode_lifetime>, table_start: u32, vtable_entry: u32) -> Result<bool, femtoflatbuffers::DecodeError> {
+                    let which_offset = decoder.decode_vtable_entry(vtable_entry)?;
+                    Ok(which_offset == 0 || decoder.decode_u8((which_offset as u32) + table_start)? == 0)
                 }
             }
         };
@@ -357,4 +1311,166 @@ pub fn flatbuffers_union_derive(input: proc_macro::TokenStream) -> proc_macro::T
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    fn variant_kinds(input: DeriveInput) -> Vec<UnionVariantKind> {
+        match input.data {
+            Data::Enum(ref data) => union_variant_kinds(data),
+            _ => unreachable!()
+        }
+    }
+
+    fn member_ids(input: DeriveInput) -> Vec<Option<u8>> {
+        variant_kinds(input).into_iter().map(|kind| match kind {
+            UnionVariantKind::Member(id) => Some(id),
+            _ => None
+        }).collect()
+    }
+
+    #[test]
+    fn implicit_ids() {
+        assert_eq!(member_ids(parse_quote!(enum U { NONE, A(X), B(Y) })), vec![None, Some(1), Some(2)]);
+        assert_eq!(member_ids(parse_quote!(enum U { A(X), B(Y) })), vec![Some(1), Some(2)]);
+        assert_eq!(member_ids(parse_quote!(enum U { A(X), #[femto(none)] Empty, B(Y) })), vec![Some(1), None, Some(2)]);
+    }
+
+    #[test]
+    fn explicit_ids() {
+        assert_eq!(member_ids(parse_quote!(enum U { NONE, #[femto(id = 5)] A(X), B(Y), #[femto(id = 2)] C(Z) })), vec![None, Some(5), Some(6), Some(2)]);
+        assert_eq!(member_ids(parse_quote!(#[repr(u8)] enum U { NONE = 0, A(X) = 3, B(Y) })), vec![None, Some(3), Some(4)]);
+        assert_eq!(member_ids(parse_quote!(enum U { A(X), #[femto(id = 0)] Empty })), vec![Some(1), None]);
+    }
+
+    #[test]
+    fn unknown_variant() {
+        let kinds = variant_kinds(parse_quote!(enum U<'a> { NONE, A(X), #[femto(unknown)] Unknown(u8, RawTable<'a>) }));
+        assert!(matches!(kinds.as_slice(), [UnionVariantKind::None, UnionVariantKind::Member(1), UnionVariantKind::Unknown]));
+    }
+
+    #[test]
+    #[should_panic(expected = "reuses id 1")]
+    fn duplicate_ids() {
+        variant_kinds(parse_quote!(enum U { NONE, A(X), #[femto(id = 1)] B(Y) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "reserved for NONE")]
+    fn zero_id() {
+        variant_kinds(parse_quote!(enum U { #[femto(id = 0)] A(X) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "doesn't fit in a u8")]
+    fn large_id() {
+        variant_kinds(parse_quote!(enum U { NONE, #[femto(id = 255)] A(X), B(Y) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "only the none variant can be a unit variant")]
+    fn extra_unit_variant() {
+        variant_kinds(parse_quote!(enum U { NONE, A(X), Other }));
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one unnamed value")]
+    fn multiple_fields() {
+        variant_kinds(parse_quote!(enum U { NONE, A(X, Y) }));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't hold a value")]
+    fn none_with_value() {
+        variant_kinds(parse_quote!(enum U { #[femto(none)] A(X) }));
+    }
+
+    #[test]
+    fn transparent_fields() {
+        let input: DeriveInput = parse_quote!(struct Id(u64););
+        let (member, _) = transparent_field(&input.data);
+        assert!(member == syn::Member::Unnamed(syn::Index::from(0)));
+        let input: DeriveInput = parse_quote!(struct Id { value: u64 });
+        let (member, _) = transparent_field(&input.data);
+        assert!(member == syn::Member::Named(format_ident!("value")));
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly one field")]
+    fn transparent_multiple_fields() {
+        let input: DeriveInput = parse_quote!(struct Range(u32, u32););
+        transparent_field(&input.data);
+    }
+
+    fn field_attributes(input: DeriveInput) -> Vec<FemtoAttributes> {
+        match input.data {
+            Data::Struct(ref data) => data.fields.iter().enumerate().map(|(index, field)| table_field_attributes(field, &field_member(index, field))).collect(),
+            _ => unreachable!()
+        }
+    }
+
+    #[test]
+    fn skipped_fields() {
+        let attributes = field_attributes(parse_quote!(struct T { a: u32, #[femto(skip)] b: u32, #[femto(skip, default = "cache::empty")] c: Cache }));
+        assert_eq!(attributes.iter().map(|attributes| attributes.skip).collect::<Vec<_>>(), vec![false, true, true]);
+        assert!(attributes[1].default.is_none());
+        assert_eq!(attributes[2].default.as_ref().map(|path| quote!(#path).to_string()), Some(quote!(cache::empty).to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "only applies together with #[femto(skip)]")]
+    fn default_without_skip() {
+        field_attributes(parse_quote!(struct T { #[femto(default = "cache::empty")] a: Cache }));
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be both")]
+    fn skip_with_codec() {
+        field_attributes(parse_quote!(struct T { #[femto(skip, with = "codec")] a: u32 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "only applies to arrays")]
+    fn vector_on_non_array() {
+        field_attributes(parse_quote!(struct T { #[femto(vector)] a: Vec<u32> }));
+    }
+
+    #[test]
+    #[should_panic(expected = "#[femto(id)] isn't supported on a table field")]
+    fn id_on_table_field() {
+        field_attributes(parse_quote!(struct T { #[femto(id = 1)] a: u32 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "#[femto(none)] isn't supported on an enum variant")]
+    fn none_on_enum_variant() {
+        let input: DeriveInput = parse_quote!(enum E { #[femto(none)] A, B });
+        match input.data {
+            Data::Enum(ref data) => parse_femto_attributes(&data.variants[0].attrs, AttributePosition::EnumVariant),
+            _ => unreachable!()
+        };
+    }
+
+    #[test]
+    #[should_panic(expected = "#[femto(transparent)] isn't supported on a union")]
+    fn transparent_on_union() {
+        let input: DeriveInput = parse_quote!(#[femto(transparent)] enum U { NONE, A(X) });
+        parse_femto_attributes(&input.attrs, AttributePosition::Union);
+    }
+
+    #[test]
+    #[should_panic(expected = "#[femto(with)] isn't supported on the field of a transparent type")]
+    fn with_on_transparent_field() {
+        let input: DeriveInput = parse_quote!(struct Id(#[femto(with = "codec")] u64););
+        transparent_field(&input.data);
+    }
+
+    #[test]
+    fn allowed_attributes() {
+        let input: DeriveInput = parse_quote!(#[femto(transparent, encode_only)] struct T { a: u32 });
+        let attributes = parse_femto_attributes(&input.attrs, AttributePosition::Table);
+        assert!(attributes.transparent && attributes.encode_only);
+        let input: DeriveInput = parse_quote!(#[femto(unknown)] struct Flags(u8););
+        assert!(parse_femto_attributes(&input.attrs, AttributePosition::BitFlags).unknown);
+        assert_eq!(member_ids(parse_quote!(enum U { #[femto(none)] Empty, #[femto(id = 3)] A(X) })), vec![None, Some(3)]);
+    }
+}
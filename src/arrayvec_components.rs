@@ -0,0 +1,4 @@
+use crate::components::{string_component_impls, vector_component_impls};
+
+vector_component_impls!([T, const CAP: usize] arrayvec::ArrayVec<T, CAP>, T, CAP);
+string_component_impls!([const CAP: usize] arrayvec::ArrayString<CAP>, CAP);
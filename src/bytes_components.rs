@@ -0,0 +1,64 @@
+use bytes::{Bytes, BytesMut};
+
+use crate::{ComponentDecode, ComponentEncode, DecodeError, Decoder, EncodeError, Encoder};
+
+macro_rules! bytes_component_impls {
+    ($($ty:ty),* $(,)?) => {$(
+        impl ComponentEncode for $ty {
+            type WorkingValue = <[u8] as ComponentEncode>::WorkingValue;
+            const VECTOR_ELEMENT: bool = false;
+
+            fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+                self[..].value_encode(encoder, table_start)
+            }
+
+            fn vtable_encode(&self, encoder: &mut Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+                self[..].vtable_encode(encoder, vtable_start, working_value)
+            }
+
+            fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+                self[..].post_encode(encoder, working_value)
+            }
+
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                self[..].vector_working_value(vector_start, value_offset)
+            }
+        }
+
+        impl <'a> ComponentDecode<'a> for $ty {
+            type WorkingValue = (u32, u16);
+            type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
+
+            fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+                let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+                Ok(((table_start, vtable_value), vtable_entry + 2))
+            }
+
+            fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+                if working_value.1 == 0 {
+                    Ok(Self::new())
+                }
+                else {
+                    // Sliced out of the decoder's Bytes when it has one, otherwise copied
+                    let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+                    let vector_len = decoder.decode_u32(vector_offset)?;
+                    Ok(decoder.decode_shared_bytes(vector_offset + 4, vector_len)?.into())
+                }
+            }
+
+            fn vector_vtable_decode(_decoder: &Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+                Err(DecodeError::InvalidData)
+            }
+
+            fn vector_len_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+                Err(DecodeError::InvalidData)
+            }
+
+            fn vector_value_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, DecodeError> {
+                Err(DecodeError::InvalidData)
+            }
+        }
+    )*};
+}
+
+bytes_component_impls!(Bytes, BytesMut);
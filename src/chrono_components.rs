@@ -0,0 +1,40 @@
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+
+use crate::{DecodeError, Decoder, EncodeError, Encoder};
+use crate::components::{primitive_component_impls, PrimitiveComponent};
+
+// Timestamps are a long of nanoseconds since the Unix epoch, naive ones taken as UTC. That covers the years 1677 to
+// 2262, anything outside can't be encoded
+impl PrimitiveComponent for DateTime<Utc> {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        encoder.encode_i64(self.timestamp_nanos_opt().ok_or(EncodeError::InvalidValue)?)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        decoder.decode_i64(offset).map(DateTime::from_timestamp_nanos)
+    }
+}
+
+impl PrimitiveComponent for NaiveDateTime {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.and_utc().do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        DateTime::<Utc>::do_decode(decoder, offset).map(|x| x.naive_utc())
+    }
+}
+
+// Durations are a long of nanoseconds, about 292 years either way
+impl PrimitiveComponent for TimeDelta {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        encoder.encode_i64(self.num_nanoseconds().ok_or(EncodeError::InvalidValue)?)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        decoder.decode_i64(offset).map(TimeDelta::nanoseconds)
+    }
+}
+
+primitive_component_impls!([] DateTime<Utc>, [] NaiveDateTime, [] TimeDelta);
@@ -1,20 +1,80 @@
+use core::marker::PhantomData;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, Wrapping
+};
+use core::time::Duration;
 use crate::{DecodeError, Decoder, EncodeError, Encoder};
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, rc::Rc};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
 pub trait ComponentEncode {
     type WorkingValue;
+    /// Anything that's written as a vector of its own can't be a vector element, flatbuffers has no vectors of
+    /// vectors. Vectors of such types fail to build rather than write a buffer nothing can read. Strings are the
+    /// exception, vectors of them are in the schema language
+    ///
+    /// ```compile_fail
+    /// use femtoflatbuffers::Table;
+    /// use femtoflatbuffers::table::TableEncode;
+    ///
+    /// #[derive(Table)]
+    /// #[femto(encode_only)]
+    /// struct Nested<'a> {
+    ///     a: &'a [&'a [u32]]
+    /// }
+    ///
+    /// let mut buffer = [0u8; 64];
+    /// let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    /// Nested {a: &[&[1, 2]]}.encode(&mut encoder).unwrap();
+    /// ```
+    const VECTOR_ELEMENT: bool = true;
     fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError>;
     fn vtable_encode(&self, encoder: &mut Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError>;
     fn post_encode(&self, _encoder: &mut Encoder, _working_value: &Self::WorkingValue) -> Result<(), EncodeError> {Ok(())}
+    // Vector elements are always present, so anything that is left out of a table when empty has to override this
+    fn vector_value_encode(&self, encoder: &mut Encoder, vector_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        self.value_encode(encoder, vector_start)
+    }
+    // Vectors don't keep the working values of their elements around, they're rebuilt from where each one was written
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue;
+    fn vector_element_alignment() -> usize {4}
+    // Unions take two vtable entries, their type and their value
+    fn vtable_entries() -> u32 {1}
+    // In a vector they're stored as a vector of types followed by a vector of values
+    fn vector_vtable_entries() -> u32 {1}
+    fn vector_type_encode(&self, _encoder: &mut Encoder) -> Result<(), EncodeError> {Ok(())}
+    // Union members are always referenced through an offset, so inline types have to move out of line
+    fn union_value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        self.vector_value_encode(encoder, table_start)
+    }
+    fn union_post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        self.post_encode(encoder, working_value)
+    }
 }
 
-pub trait ComponentDecode {
+pub trait ComponentDecode<'a> {
     type WorkingValue;
     type VectorWorkingValue;
-    fn vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError>;
-    fn value_decode(decoder: &Decoder, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> where Self: Sized;
-    fn vector_vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError>;
-    fn vector_len_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError>;
-    fn vector_value_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> where Self: Sized;
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError>;
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> where Self: Sized;
+    fn vector_vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError>;
+    fn vector_len_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError>;
+    fn vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> where Self: Sized;
+    fn vtable_entries() -> u32 {1}
+    fn vector_vtable_entries() -> u32 {1}
+    fn is_absent(decoder: &Decoder<'a>, _table_start: u32, vtable_entry: u32) -> Result<bool, DecodeError> {
+        Ok(decoder.decode_vtable_entry(vtable_entry)? == 0)
+    }
+    fn union_value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> where Self: Sized {
+        Self::value_decode(decoder, working_value)
+    }
+    fn union_vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> where Self: Sized {
+        Self::vector_value_decode(decoder, working_value, idx)
+    }
 }
 
 pub trait PrimitiveComponent {
@@ -22,104 +82,411 @@ pub trait PrimitiveComponent {
     fn size() -> usize;
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError>;
     fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> where Self: Sized;
+    // The value of a scalar or struct that isn't in the table, read as all zeroes as flatc does, so types without a
+    // zero value still reject it. Anything wider than 16 bytes has to build it from its parts instead
+    fn absent_decode() -> Result<Self, DecodeError> where Self: Sized {
+        Self::do_decode(&Decoder::new(&[0; 16]), 0)
+    }
+}
+
+impl PrimitiveComponent for f64 {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_f64(*self)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_f64(offset)}
+}
+
+impl PrimitiveComponent for f32 {
+    fn alignment() -> usize {4}
+    fn size() -> usize {4}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_f32(*self)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_f32(offset)}
 }
 
 impl PrimitiveComponent for u32 {
     fn alignment() -> usize {4}
     fn size() -> usize {4}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_u32(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_u32(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_u32(offset)}
 }
 
 impl PrimitiveComponent for u64 {
     fn alignment() -> usize {8}
     fn size() -> usize {8}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_u64(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_u64(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_u64(offset)}
 }
 
 impl PrimitiveComponent for i64 {
     fn alignment() -> usize {8}
     fn size() -> usize {8}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_i64(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_i64(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_i64(offset)}
 }
 
 impl PrimitiveComponent for i32 {
     fn alignment() -> usize {4}
     fn size() -> usize {4}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_i32(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_i32(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_i32(offset)}
 }
 
 impl PrimitiveComponent for u16 {
     fn alignment() -> usize {2}
     fn size() -> usize {2}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_u16(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_u16(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_u16(offset)}
 }
 
 impl PrimitiveComponent for i16 {
     fn alignment() -> usize {2}
     fn size() -> usize {2}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_i16(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_i16(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_i16(offset)}
 }
 
 impl PrimitiveComponent for u8 {
     fn alignment() -> usize {1}
     fn size() -> usize {1}
     fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_u8(*self)}
-    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {Ok(decoder.decode_u8(offset)?)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_u8(offset)}
 }
 
-impl <T: PrimitiveComponent> ComponentEncode for T {
-    type WorkingValue = (u32, u32);
-    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
-        let value_offset = self.do_encode(encoder)?;
+impl PrimitiveComponent for i8 {
+    fn alignment() -> usize {1}
+    fn size() -> usize {1}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_i8(*self)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_i8(offset)}
+}
+
+impl PrimitiveComponent for bool {
+    fn alignment() -> usize {1}
+    fn size() -> usize {1}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_bool(*self)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {decoder.decode_bool(offset)}
+}
+
+// Wide integers are a struct of two ulongs, low half first, which keeps the bytes of the little-endian value:
+// struct U128 { lo: ulong; hi: ulong; }. i128 uses the same struct holding its two's complement bits
+impl PrimitiveComponent for u128 {
+    fn alignment() -> usize {8}
+    fn size() -> usize {16}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        let start = encoder.encode_u64(*self as u64)?;
+        encoder.encode_u64((*self >> 64) as u64)?;
+        Ok(start)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        let lo = decoder.decode_u64(offset)?;
+        let hi = decoder.decode_u64(offset + 8)?;
+        Ok(((hi as u128) << 64) | lo as u128)
+    }
+}
+
+impl PrimitiveComponent for i128 {
+    fn alignment() -> usize {8}
+    fn size() -> usize {16}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {(*self as u128).do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {u128::do_decode(decoder, offset).map(|x| x as i128)}
+}
+
+// A char is its code point as a uint, anything that isn't a valid code point is rejected
+impl PrimitiveComponent for char {
+    fn alignment() -> usize {4}
+    fn size() -> usize {4}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {encoder.encode_u32(*self as u32)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        char::from_u32(decoder.decode_u32(offset)?).ok_or(DecodeError::InvalidValue)
+    }
+}
+
+// Non-zero integers are the underlying scalar, a zero is rejected
+macro_rules! non_zero_primitive_impls {
+    ($($ty:ty: $scalar:ty),* $(,)?) => {$(
+        impl PrimitiveComponent for $ty {
+            fn alignment() -> usize {<$scalar>::alignment()}
+            fn size() -> usize {<$scalar>::size()}
+            fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.get().do_encode(encoder)}
+            fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+                <$ty>::new(<$scalar>::do_decode(decoder, offset)?).ok_or(DecodeError::InvalidValue)
+            }
+        }
+    )*};
+}
+
+non_zero_primitive_impls!(
+    NonZeroU8: u8, NonZeroI8: i8, NonZeroU16: u16, NonZeroI16: i16, NonZeroU32: u32, NonZeroI32: i32,
+    NonZeroU64: u64, NonZeroI64: i64, NonZeroU128: u128, NonZeroI128: i128
+);
+
+impl <T: PrimitiveComponent> PrimitiveComponent for Wrapping<T> {
+    fn alignment() -> usize {T::alignment()}
+    fn size() -> usize {T::size()}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.0.do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {T::do_decode(decoder, offset).map(Wrapping)}
+    fn absent_decode() -> Result<Self, DecodeError> {T::absent_decode().map(Wrapping)}
+}
+
+// Same layout as flatc gives struct Duration { secs: ulong; nanos: uint; }, including the padding at the end.
+// Nanoseconds of a second or more are rejected
+impl PrimitiveComponent for Duration {
+    fn alignment() -> usize {8}
+    fn size() -> usize {16}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        let start = encoder.encode_u64(self.as_secs())?;
+        encoder.encode_u32(self.subsec_nanos())?;
+        encoder.pad_to_align(8)?;
+        Ok(start)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        let secs = decoder.decode_u64(offset)?;
+        let nanos = decoder.decode_u32(offset + 8)?;
+        if nanos >= 1_000_000_000 {
+            return Err(DecodeError::InvalidValue);
+        }
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+// Addresses of a known family are a struct wrapping their octets, struct Ipv4 { octets: [ubyte:4]; } and
+// struct Ipv6 { octets: [ubyte:16]; }
+impl PrimitiveComponent for Ipv4Addr {
+    fn alignment() -> usize {1}
+    fn size() -> usize {4}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.octets().do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {<[u8; 4]>::do_decode(decoder, offset).map(Ipv4Addr::from)}
+}
+
+impl PrimitiveComponent for Ipv6Addr {
+    fn alignment() -> usize {1}
+    fn size() -> usize {16}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.octets().do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {<[u8; 16]>::do_decode(decoder, offset).map(Ipv6Addr::from)}
+}
+
+/// Fixed-size arrays are laid out inline like a struct, so as a table field they match a struct wrapping the array.
+/// A `[T; N]` table field with `#[femto(vector)]` is written as `[T]` instead, and needs exactly `N` elements to
+/// decode
+impl <T: PrimitiveComponent, const N: usize> PrimitiveComponent for [T; N] {
+    fn alignment() -> usize {T::alignment()}
+    fn size() -> usize {T::size() * N}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        encoder.pad_to_align(T::alignment())?;
+        let start = encoder.used_bytes();
+        for x in self.iter() {
+            x.do_encode(encoder)?;
+        }
+        Ok(start)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        // Bounds check the whole array up front
+        decoder.decode_bytes(offset, Self::size() as u32)?;
+        let mut error = None;
+        let values: [Option<T>; N] = core::array::from_fn(|idx| {
+            T::do_decode(decoder, offset + (idx*T::size()) as u32).map_err(|err| error = Some(err)).ok()
+        });
+        match error {
+            Some(err) => Err(err),
+            None => Ok(values.map(|x| x.unwrap()))
+        }
+    }
+    fn absent_decode() -> Result<Self, DecodeError> {
+        let mut error = None;
+        let values: [Option<T>; N] = core::array::from_fn(|_| T::absent_decode().map_err(|err| error = Some(err)).ok());
+        match error {
+            Some(err) => Err(err),
+            None => Ok(values.map(|x| x.unwrap()))
+        }
+    }
+}
+
+// Every PrimitiveComponent is handled the same way in tables and vectors, the derives forward their component impls here as well
+#[doc(hidden)]
+pub mod primitive {
+    use super::PrimitiveComponent;
+    use crate::{DecodeError, Decoder, EncodeError, Encoder};
+
+    pub fn value_encode<T: PrimitiveComponent>(value: &T, encoder: &mut Encoder, table_start: u32) -> Result<(u32, u32), EncodeError> {
+        let value_offset = value.do_encode(encoder)?;
         Ok((table_start, value_offset))
     }
-    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+
+    pub fn vtable_encode(encoder: &mut Encoder, working_value: &(u32, u32)) -> Result<(), EncodeError> {
         encoder.encode_u16((working_value.1 - working_value.0) as u16)?;
         Ok(())
     }
-}
 
-impl <T: PrimitiveComponent> ComponentDecode for T {
-    type WorkingValue = (u32, u16);
-    type VectorWorkingValue = Self::WorkingValue;
-    fn vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
-        let vtable_entry_value = decoder.decode_u16(vtable_entry)?;
+    pub fn vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<((u32, u16), u32), DecodeError> {
+        let vtable_entry_value = decoder.decode_vtable_entry(vtable_entry)?;
         Ok(((table_start, vtable_entry_value), vtable_entry+2))
     }
-    fn value_decode(decoder: &Decoder, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
-        Ok(T::do_decode(decoder, working_value.0 + working_value.1 as u32)?)
-    }
-    fn vector_vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
-        let vtable_entry_value = decoder.decode_u16(vtable_entry)?;
-        Ok(((table_start, vtable_entry_value), vtable_entry+2))
+
+    pub fn value_decode<T: PrimitiveComponent>(decoder: &Decoder, working_value: &(u32, u16)) -> Result<T, DecodeError> {
+        // Scalars and structs flatc left out because they held the default, or that the writer's schema didn't have yet
+        if working_value.1 == 0 {
+            return T::absent_decode();
+        }
+        T::do_decode(decoder, working_value.0 + working_value.1 as u32)
     }
-    fn vector_len_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
-        let vector_offset = (decoder.decode_i32(working_value.0 + working_value.1 as u32)? + working_value.0 as i32 + working_value.1 as i32) as u32;
+
+    pub fn vector_len_decode(decoder: &Decoder, working_value: &(u32, u16)) -> Result<usize, DecodeError> {
+        let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
         Ok(decoder.decode_u32(vector_offset)? as usize)
     }
-    fn vector_value_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError>
-    where
-        Self: Sized
-    {
-        let vector_offset = (decoder.decode_i32(working_value.0 + working_value.1 as u32)? + working_value.0 as i32 + working_value.1 as i32) as u32;
-        T::do_decode(decoder, (vector_offset+4) + (idx*Self::size()) as u32)
+
+    pub fn vector_value_decode<T: PrimitiveComponent>(decoder: &Decoder, working_value: &(u32, u16), idx: usize) -> Result<T, DecodeError> {
+        let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+        T::do_decode(decoder, decoder.vector_element(vector_offset, idx, T::size())?)
+    }
+
+    pub fn union_value_encode(encoder: &mut Encoder, table_start: u32) -> Result<(u32, u32), EncodeError> {
+        let value_offset = encoder.encode_i32(0)?;
+        Ok((table_start, value_offset))
+    }
+
+    pub fn union_post_encode<T: PrimitiveComponent>(value: &T, encoder: &mut Encoder, working_value: &(u32, u32)) -> Result<(), EncodeError> {
+        let value_start = value.do_encode(encoder)?;
+        encoder.encode_i32_at(working_value.1, (value_start - working_value.1) as i32)
+    }
+
+    pub fn union_value_decode<T: PrimitiveComponent>(decoder: &Decoder, working_value: &(u32, u16)) -> Result<T, DecodeError> {
+        let value_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+        T::do_decode(decoder, value_offset)
+    }
+
+    pub fn union_vector_value_decode<T: PrimitiveComponent>(decoder: &Decoder, working_value: &(u32, u16), idx: usize) -> Result<T, DecodeError> {
+        let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+        let value_offset = decoder.decode_offset(decoder.vector_element(vector_offset, idx, 4)?)?;
+        T::do_decode(decoder, value_offset)
+    }
+}
+
+// Fields with #[femto(with = "module")] are written as whatever module::encode returns them as. Decoding goes through
+// module::decode, which is also what picks the type to read from the buffer
+#[doc(hidden)]
+pub mod with {
+    use super::ComponentDecode;
+    use crate::{DecodeError, Decoder};
+
+    pub fn vtable_decode<'a, W: ComponentDecode<'a>, T>(_decode: impl FnOnce(W) -> Result<T, DecodeError>, decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(W::WorkingValue, u32), DecodeError> {
+        W::vtable_decode(decoder, table_start, vtable_entry)
+    }
+
+    pub fn value_decode<'a, W: ComponentDecode<'a>, T>(decode: impl FnOnce(W) -> Result<T, DecodeError>, decoder: &Decoder<'a>, working_value: &W::WorkingValue) -> Result<T, DecodeError> {
+        decode(W::value_decode(decoder, working_value)?)
+    }
+}
+
+// Array fields with #[femto(vector)] are written as the slice of their elements, and decode from exactly as many
+#[doc(hidden)]
+pub mod array_vector {
+    use super::ComponentDecode;
+    use crate::{DecodeError, Decoder};
+
+    pub fn vtable_decode<'a, T: ComponentDecode<'a>>(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Option<T::VectorWorkingValue>, u32), DecodeError> {
+        if decoder.decode_vtable_entry(vtable_entry)? == 0 {
+            return Ok((None, vtable_entry + 2*T::vector_vtable_entries()));
+        }
+        let (working_value, next_offset) = T::vector_vtable_decode(decoder, table_start, vtable_entry)?;
+        Ok((Some(working_value), next_offset))
+    }
+
+    pub fn value_decode<'a, T: ComponentDecode<'a>, const N: usize>(decoder: &Decoder<'a>, working_value: &Option<T::VectorWorkingValue>) -> Result<[T; N], DecodeError> {
+        // Empty vectors aren't written at all
+        let len = match working_value {
+            Some(working_value) => T::vector_len_decode(decoder, working_value)?,
+            None => 0
+        };
+        if len != N {
+            return Err(DecodeError::InvalidData);
+        }
+        let mut error = None;
+        let values: [Option<T>; N] = core::array::from_fn(|idx| {
+            T::vector_value_decode(decoder, working_value.as_ref()?, idx).map_err(|err| error = Some(err)).ok()
+        });
+        match error {
+            Some(err) => Err(err),
+            None => Ok(values.map(Option::unwrap))
+        }
     }
 }
 
+macro_rules! primitive_component_impls {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {$(
+        impl <$($generics)*> $crate::ComponentEncode for $ty {
+            type WorkingValue = (u32, u32);
+            fn value_encode(&self, encoder: &mut $crate::Encoder, table_start: u32) -> Result<Self::WorkingValue, $crate::EncodeError> {
+                $crate::components::primitive::value_encode(self, encoder, table_start)
+            }
+            fn vtable_encode(&self, encoder: &mut $crate::Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), $crate::EncodeError> {
+                $crate::components::primitive::vtable_encode(encoder, working_value)
+            }
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                (vector_start, value_offset)
+            }
+            fn vector_element_alignment() -> usize {
+                <Self as $crate::components::PrimitiveComponent>::alignment()
+            }
+            fn union_value_encode(&self, encoder: &mut $crate::Encoder, table_start: u32) -> Result<Self::WorkingValue, $crate::EncodeError> {
+                $crate::components::primitive::union_value_encode(encoder, table_start)
+            }
+            fn union_post_encode(&self, encoder: &mut $crate::Encoder, working_value: &Self::WorkingValue) -> Result<(), $crate::EncodeError> {
+                $crate::components::primitive::union_post_encode(self, encoder, working_value)
+            }
+        }
+
+        impl <'a, $($generics)*> $crate::ComponentDecode<'a> for $ty {
+            type WorkingValue = (u32, u16);
+            type VectorWorkingValue = Self::WorkingValue;
+            fn vtable_decode(decoder: &$crate::Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), $crate::DecodeError> {
+                $crate::components::primitive::vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, $crate::DecodeError> {
+                $crate::components::primitive::value_decode(decoder, working_value)
+            }
+            fn vector_vtable_decode(decoder: &$crate::Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), $crate::DecodeError> {
+                $crate::components::primitive::vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn vector_len_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, $crate::DecodeError> {
+                $crate::components::primitive::vector_len_decode(decoder, working_value)
+            }
+            fn vector_value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, $crate::DecodeError> {
+                $crate::components::primitive::vector_value_decode(decoder, working_value, idx)
+            }
+            fn union_value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, $crate::DecodeError> {
+                $crate::components::primitive::union_value_decode(decoder, working_value)
+            }
+            fn union_vector_value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, $crate::DecodeError> {
+                $crate::components::primitive::union_vector_value_decode(decoder, working_value, idx)
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "uuid", feature = "chrono", feature = "time"))]
+pub(crate) use primitive_component_impls;
+
+primitive_component_impls!(
+    [] f64, [] f32, [] u64, [] i64, [] u32, [] i32, [] u16, [] i16, [] u8, [] i8, [] bool,
+    [] u128, [] i128, [] char, [] Duration, [] Ipv4Addr, [] Ipv6Addr,
+    [] NonZeroU8, [] NonZeroI8, [] NonZeroU16, [] NonZeroI16, [] NonZeroU32, [] NonZeroI32,
+    [] NonZeroU64, [] NonZeroI64, [] NonZeroU128, [] NonZeroI128,
+    [T: PrimitiveComponent] Wrapping<T>,
+    [T: PrimitiveComponent, const N: usize] [T; N]
+);
+
 impl <T: ComponentEncode> ComponentEncode for Option<T> {
     type WorkingValue = Option<T::WorkingValue>;
+    const VECTOR_ELEMENT: bool = T::VECTOR_ELEMENT;
     fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
         match self {
             Some(x) => Ok(Some(x.value_encode(encoder, table_start)?)),
             None => Ok(None)
         }
     }
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        self.as_ref().map(|x| x.vector_working_value(vector_start, value_offset))
+    }
     fn vtable_encode(&self, encoder: &mut Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
         match (self, working_value) {
             (Some(inner_self), Some(inner_working_value)) => {
@@ -127,7 +494,9 @@ impl <T: ComponentEncode> ComponentEncode for Option<T> {
                 Ok(())
             }
             (None, None) => {
-                encoder.encode_u16(0)?;
+                for _ in 0..T::vtable_entries() {
+                    encoder.encode_u16(0)?;
+                }
                 Ok(())
             }
             _ => {
@@ -151,27 +520,26 @@ impl <T: ComponentEncode> ComponentEncode for Option<T> {
     }
 }
 
-impl <T: ComponentDecode> ComponentDecode for Option<T> {
+impl <'a, T: ComponentDecode<'a>> ComponentDecode<'a> for Option<T> {
     type WorkingValue = Option<T::WorkingValue>;
     type VectorWorkingValue = Option<T::VectorWorkingValue>;
-    fn vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
-        let value = decoder.decode_u16(vtable_entry)?;
-        match value {
-            0 => Ok((None, vtable_entry+2)),
-            _ => {
-                let (working_value, next_offset) = T::vtable_decode(decoder, table_start, vtable_entry)?;
-                Ok((Some(working_value), next_offset))
-            }
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        if T::is_absent(decoder, table_start, vtable_entry)? {
+            Ok((None, vtable_entry + 2*T::vtable_entries()))
+        }
+        else {
+            let (working_value, next_offset) = T::vtable_decode(decoder, table_start, vtable_entry)?;
+            Ok((Some(working_value), next_offset))
         }
     }
-    fn value_decode(decoder: &Decoder, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
         match working_value {
             Some(working_value_inner) => Ok(Some(T::value_decode(decoder, working_value_inner)?)),
             None => Ok(None)
         }
     }
-    fn vector_vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
-        let value = decoder.decode_u16(vtable_entry)?;
+    fn vector_vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        let value = decoder.decode_vtable_entry(vtable_entry)?;
         match value {
             0 => Ok((None, vtable_entry+2)),
             _ => {
@@ -180,7 +548,7 @@ impl <T: ComponentDecode> ComponentDecode for Option<T> {
             }
         }
     }
-    fn vector_len_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+    fn vector_len_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
         match working_value {
             Some(working_value_inner) => {
                 T::vector_len_decode(decoder, working_value_inner)
@@ -190,7 +558,7 @@ impl <T: ComponentDecode> ComponentDecode for Option<T> {
             }
         }
     }
-    fn vector_value_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError>
+    fn vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError>
     where
         Self: Sized,
     {
@@ -205,96 +573,1148 @@ impl <T: ComponentDecode> ComponentDecode for Option<T> {
     }
 }
 
-#[cfg(feature = "alloc")]
-impl <T: ComponentEncode> ComponentEncode for alloc::vec::Vec<T> {
-    type WorkingValue = Option<(u32, u32)>;
+// The offsets of a vector field, one per vtable entry of the element type
+pub(crate) fn vector_offsets_encode<T: ComponentEncode + ?Sized>(len: usize, encoder: &mut Encoder, table_start: u32) -> Result<Option<(u32, u32)>, EncodeError> {
+    const { assert!(T::VECTOR_ELEMENT, "flatbuffers has no vectors of vectors, this element type is written as a vector of its own") };
+    if len != 0 {
+        let value_offset = encoder.encode_i32(0)?;
+        for _ in 1..T::vector_vtable_entries() {
+            encoder.encode_i32(0)?;
+        }
+        Ok(Some((table_start, value_offset)))
+    }
+    else {
+        Ok(None)
+    }
+}
 
-    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
-        if !self.is_empty() {
-            let value_offset = encoder.encode_i32(0)?;
-            Ok(Some((table_start, value_offset)))
+pub(crate) fn vector_vtable_encode<T: ComponentEncode + ?Sized>(encoder: &mut Encoder, working_value: &Option<(u32, u32)>) -> Result<(), EncodeError> {
+    for idx in 0..T::vector_vtable_entries() {
+        match working_value {
+            Some((table_start, value_offset)) => encoder.encode_u16((value_offset + idx*4 - table_start) as u16)?,
+            None => encoder.encode_u16(0)?
+        };
+    }
+    Ok(())
+}
+
+// Writes the type vector for unions, returns the offset to patch with the value vector
+fn vector_types_encode<I: Iterator<Item: ComponentEncode>>(items: I, len: usize, encoder: &mut Encoder, value_offset: u32) -> Result<u32, EncodeError> {
+    if I::Item::vector_vtable_entries() == 1 {
+        return Ok(value_offset);
+    }
+    let types_start = encoder.encode_vector_len(len as u32, 1)?;
+    for x in items {
+        x.vector_type_encode(encoder)?;
+    }
+    encoder.encode_i32_at(value_offset, (types_start - value_offset) as i32)?;
+    Ok(value_offset + 4)
+}
+
+// Writes the elements of a vector field. Nothing is kept per element, so the items are walked once per pass and have
+// to come out the same every time
+pub(crate) fn vector_post_encode<I>(items: I, encoder: &mut Encoder, working_value: &Option<(u32, u32)>) -> Result<(), EncodeError>
+where
+    I: ExactSizeIterator<Item: ComponentEncode> + Clone
+{
+    if let Some((_table_start, value_offset)) = working_value {
+        let len = items.len();
+        let value_offset = vector_types_encode(items.clone(), len, encoder, *value_offset)?;
+        let global_list_start = encoder.encode_vector_len(len as u32, I::Item::vector_element_alignment())?;
+
+        // Every element has to take up the same space, where each one went is worked out from that. Something
+        // that writes nothing, like a None, can't be an element
+        let mut written = 0;
+        let mut element_size = 0;
+        for x in items.clone() {
+            let element_start = encoder.used_bytes();
+            x.vector_value_encode(encoder, global_list_start)?;
+            let size = encoder.used_bytes() - element_start;
+            if size == 0 || (written != 0 && size != element_size) {
+                return Err(EncodeError::InvalidStructure);
+            }
+            element_size = size;
+            written += 1;
         }
-        else {
-            Ok(None)
+        if written != len || len == 0 {
+            return Err(EncodeError::InvalidStructure);
+        }
+
+        let elements_start = global_list_start + 4;
+        for (idx, x) in items.enumerate() {
+            let working_value = x.vector_working_value(global_list_start, elements_start + idx as u32 * element_size);
+            x.post_encode(encoder, &working_value)?;
         }
+
+        encoder.encode_i32_at(value_offset, (global_list_start - value_offset) as i32)?;
     }
+    Ok(())
+}
 
-    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
-        match working_value {
-            Some((table_start, value_offset)) => {
-                encoder.encode_u16((value_offset - table_start) as u16)?;
-                Ok(())
+// Decodes a vector field into any collection, keeping at most the first capacity elements like heapless does
+#[cfg(any(feature = "alloc", feature = "heapless", feature = "arrayvec", feature = "smallvec", feature = "tinyvec"))]
+pub(crate) fn vector_field_vtable_decode<'a, T: ComponentDecode<'a>>(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Option<T::VectorWorkingValue>, u32), DecodeError> {
+    let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+    if vtable_value == 0 {
+        Ok((None, vtable_entry + 2*T::vector_vtable_entries()))
+    }
+    else {
+        let (working_value, next_offset) = T::vector_vtable_decode(decoder, table_start, vtable_entry)?;
+        Ok((Some(working_value), next_offset))
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "heapless", feature = "arrayvec", feature = "smallvec", feature = "tinyvec"))]
+pub(crate) fn vector_field_decode<'a, T: ComponentDecode<'a>, C: FromIterator<T>>(decoder: &Decoder<'a>, working_value: &Option<T::VectorWorkingValue>, capacity: usize) -> Result<C, DecodeError> {
+    match working_value {
+        Some(working_value) => {
+            let vector_len = T::vector_len_decode(decoder, working_value)?;
+            (0..vector_len.min(capacity)).map(|idx| T::vector_value_decode(decoder, working_value, idx)).collect()
+        }
+        None => Ok(core::iter::empty().collect())
+    }
+}
+
+// Vector containers are all written through the slice they deref to, and decoded by collecting the elements
+#[cfg(any(feature = "alloc", feature = "heapless", feature = "arrayvec", feature = "smallvec", feature = "tinyvec"))]
+macro_rules! vector_component_impls {
+    ($([$($generics:tt)*] $ty:ty, $element:ty, $capacity:expr);* $(;)?) => {$(
+        impl <$($generics)*> $crate::ComponentEncode for $ty where $element: $crate::ComponentEncode {
+            type WorkingValue = Option<(u32, u32)>;
+            const VECTOR_ELEMENT: bool = false;
+
+            fn value_encode(&self, encoder: &mut $crate::Encoder, table_start: u32) -> Result<Self::WorkingValue, $crate::EncodeError> {
+                $crate::components::vector_offsets_encode::<$element>(self.len(), encoder, table_start)
             }
-            None => {
-                encoder.encode_u16(0)?;
-                Ok(())
+
+            fn vtable_encode(&self, encoder: &mut $crate::Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), $crate::EncodeError> {
+                $crate::components::vector_vtable_encode::<$element>(encoder, working_value)
+            }
+
+            fn post_encode(&self, encoder: &mut $crate::Encoder, working_value: &Self::WorkingValue) -> Result<(), $crate::EncodeError> {
+                $crate::components::vector_post_encode(self.iter(), encoder, working_value)
+            }
+
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                Some((vector_start, value_offset))
             }
         }
-    }
 
-    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
-        if let Some((_table_start, value_offset)) = working_value {
-            let global_list_start = encoder.encode_u32(self.len() as u32)?;
+        impl <'a, $($generics)*> $crate::ComponentDecode<'a> for $ty where $element: $crate::ComponentDecode<'a> {
+            type WorkingValue = Option<<$element as $crate::ComponentDecode<'a>>::VectorWorkingValue>;
+            type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
+
+            fn vtable_decode(decoder: &$crate::Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), $crate::DecodeError> {
+                $crate::components::vector_field_vtable_decode::<$element>(decoder, table_start, vtable_entry)
+            }
+
+            fn value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, $crate::DecodeError> {
+                $crate::components::vector_field_decode(decoder, working_value, $capacity)
+            }
 
-            let mut working_values = alloc::vec::Vec::with_capacity(self.len());
-            for x in self.iter() {
-                let working_value = x.value_encode(encoder, global_list_start)?;
-                working_values.push(working_value);
+            fn vector_vtable_decode(_decoder: &$crate::Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), $crate::DecodeError> {
+                Err($crate::DecodeError::InvalidData)
             }
 
-            for (working_value, x) in working_values.into_iter().zip(self.iter()) {
-                x.post_encode(encoder, &working_value)?;
+            fn vector_len_decode(_decoder: &$crate::Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, $crate::DecodeError> {
+                Err($crate::DecodeError::InvalidData)
             }
 
-            encoder.encode_i32_at(*value_offset, (global_list_start - value_offset) as i32)?;
-            Ok(())
+            fn vector_value_decode(_decoder: &$crate::Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, $crate::DecodeError> {
+                Err($crate::DecodeError::InvalidData)
+            }
         }
-        else {
-            Ok(())
+    )*};
+}
+#[cfg(any(feature = "heapless", feature = "arrayvec", feature = "smallvec", feature = "tinyvec"))]
+pub(crate) use vector_component_impls;
+
+#[cfg(feature = "alloc")]
+vector_component_impls!([T] alloc::vec::Vec<T>, T, usize::MAX);
+
+// A map entry is a table with the key as its first field and the value as its second, i.e.
+// `table Entry { key: K (key); value: V; }`, and maps are vectors of them sorted by key
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+pub(crate) struct MapEntry<'m, K, V> {
+    key: &'m K,
+    value: &'m V
+}
+
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+impl <K: ComponentEncode, V: ComponentEncode> MapEntry<'_, K, V> {
+    fn table_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        let table_start = encoder.encode_i32(0)?;
+        // Keys are always written, even empty strings, as flatc's lookup expects them to be there
+        let key_working_value = self.key.vector_value_encode(encoder, table_start)?;
+        let value_working_value = self.value.value_encode(encoder, table_start)?;
+        let table_end = encoder.used_bytes();
+        let vtable_start = encoder.encode_u16(0)?;
+        encoder.encode_i32_at(table_start, -((vtable_start - table_start) as i32))?;
+        encoder.encode_u16((table_end - table_start) as u16)?;
+        self.key.vtable_encode(encoder, vtable_start, &key_working_value)?;
+        self.value.vtable_encode(encoder, vtable_start, &value_working_value)?;
+        encoder.encode_u16_at(vtable_start, (encoder.used_bytes() - vtable_start) as u16)?;
+        self.key.post_encode(encoder, &key_working_value)?;
+        self.value.post_encode(encoder, &value_working_value)?;
+        Ok(table_start)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+impl <K: ComponentEncode, V: ComponentEncode> ComponentEncode for MapEntry<'_, K, V> {
+    type WorkingValue = (u32, u32);
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        let value_offset = encoder.encode_i32(0)?;
+        Ok((table_start, value_offset))
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        encoder.encode_u16((working_value.1 - working_value.0) as u16)?;
+        Ok(())
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        let table_start = self.table_encode(encoder)?;
+        encoder.encode_i32_at(working_value.1, (table_start - working_value.1) as i32)
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        (vector_start, value_offset)
+    }
+}
+
+// Writes the entries of a map that isn't kept in key order
+#[cfg(any(feature = "std", feature = "heapless"))]
+pub(crate) fn unsorted_map_post_encode<K: ComponentEncode + Ord, V: ComponentEncode>(entries: &mut [(&K, &V)], encoder: &mut Encoder, working_value: &Option<(u32, u32)>) -> Result<(), EncodeError> {
+    entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+    vector_post_encode(entries.iter().map(|&(key, value)| MapEntry {key, value}), encoder, working_value)
+}
+
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+pub(crate) fn map_vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Option<u32>, u32), DecodeError> {
+    let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+    if vtable_value == 0 {
+        Ok((None, vtable_entry+2))
+    }
+    else {
+        Ok((Some(decoder.decode_offset(table_start + vtable_value as u32)?), vtable_entry+2))
+    }
+}
+
+// Hands every entry to insert, which returns the value it replaced if the key was already there
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+pub(crate) fn map_entries_decode<'a, K, V, F>(decoder: &Decoder<'a>, working_value: &Option<u32>, mut insert: F) -> Result<(), DecodeError>
+where
+    K: ComponentDecode<'a>,
+    V: ComponentDecode<'a>,
+    F: FnMut(K, V) -> Result<Option<V>, DecodeError>
+{
+    if let Some(vector_offset) = working_value {
+        let decoder = decoder.nested()?;
+        let len = decoder.decode_u32(*vector_offset)?;
+        for idx in 0..len {
+            let table_start = decoder.decode_offset(decoder.vector_element(*vector_offset, idx as usize, 4)?)?;
+            let vtable_offset = decoder.decode_vtable_start(table_start)?;
+            let entry_decoder = decoder.with_vtable(vtable_offset, decoder.decode_u16(vtable_offset)?);
+            let (key_working_value, offset) = K::vtable_decode(&entry_decoder, table_start, vtable_offset + 4)?;
+            let (value_working_value, _) = V::vtable_decode(&entry_decoder, table_start, offset)?;
+            let key = K::value_decode(&entry_decoder, &key_working_value)?;
+            let value = V::value_decode(&entry_decoder, &value_working_value)?;
+            if insert(key, value)?.is_some() {
+                return Err(DecodeError::DuplicateKey);
+            }
         }
     }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+impl <K: ComponentEncode, V: ComponentEncode> ComponentEncode for alloc::collections::BTreeMap<K, V> {
+    type WorkingValue = Option<(u32, u32)>;
+    const VECTOR_ELEMENT: bool = false;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        vector_offsets_encode::<MapEntry<K, V>>(self.len(), encoder, table_start)
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_vtable_encode::<MapEntry<K, V>>(encoder, working_value)
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_post_encode(self.iter().map(|(key, value)| MapEntry {key, value}), encoder, working_value)
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
 }
 
 #[cfg(feature = "alloc")]
-impl <T: ComponentDecode> ComponentDecode for alloc::vec::Vec<T> {
-    type WorkingValue = Option<T::VectorWorkingValue>;
+impl <'a, K: ComponentDecode<'a> + Ord, V: ComponentDecode<'a>> ComponentDecode<'a> for alloc::collections::BTreeMap<K, V> {
+    type WorkingValue = Option<u32>;
     type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
-    
-    fn vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
-        let vtable_value = decoder.decode_u16(vtable_entry)?;
-        if vtable_value == 0 {
-            Ok((None, vtable_entry+2))
-        }
-        else {
-            let (working_value, next_offset) = T::vector_vtable_decode(decoder, table_start, vtable_entry)?;
-            Ok((Some(working_value), next_offset))
-        }
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        map_vtable_decode(decoder, table_start, vtable_entry)
     }
-    fn value_decode(decoder: &Decoder, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
-        if let Some(working_value) = working_value {
-            let vector_len = T::vector_len_decode(decoder, working_value)?;
-            let mut result = alloc::vec::Vec::with_capacity(vector_len as usize);
-            for idx in 0..vector_len {
-                result.push(T::vector_value_decode(decoder, working_value, idx)?);
-            }
-            Ok(result)
-        } else {
-            Ok(alloc::vec::Vec::new())
-        }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        let mut result = alloc::collections::BTreeMap::new();
+        map_entries_decode(decoder, working_value, |key, value| Ok(result.insert(key, value)))?;
+        Ok(result)
     }
 
-    fn vector_vtable_decode(decoder: &Decoder, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+    fn vector_vtable_decode(_decoder: &Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
         Err(DecodeError::InvalidData)
     }
 
-    fn vector_len_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+    fn vector_len_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
         Err(DecodeError::InvalidData)
     }
 
-    fn vector_value_decode(decoder: &Decoder, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError>
-    where
-        Self: Sized
-    {
+    fn vector_value_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, DecodeError> {
         Err(DecodeError::InvalidData)
     }
 }
+
+#[cfg(feature = "std")]
+impl <K: ComponentEncode + Ord, V: ComponentEncode, S> ComponentEncode for std::collections::HashMap<K, V, S> {
+    type WorkingValue = Option<(u32, u32)>;
+    const VECTOR_ELEMENT: bool = false;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        vector_offsets_encode::<MapEntry<K, V>>(self.len(), encoder, table_start)
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_vtable_encode::<MapEntry<K, V>>(encoder, working_value)
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        let mut entries: alloc::vec::Vec<_> = self.iter().collect();
+        unsorted_map_post_encode(&mut entries, encoder, working_value)
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
+}
+
+#[cfg(feature = "std")]
+impl <'a, K, V, S> ComponentDecode<'a> for std::collections::HashMap<K, V, S>
+where
+    K: ComponentDecode<'a> + Eq + core::hash::Hash,
+    V: ComponentDecode<'a>,
+    S: core::hash::BuildHasher + Default
+{
+    type WorkingValue = Option<u32>;
+    type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        map_vtable_decode(decoder, table_start, vtable_entry)
+    }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        let mut result = std::collections::HashMap::default();
+        map_entries_decode(decoder, working_value, |key, value| Ok(result.insert(key, value)))?;
+        Ok(result)
+    }
+
+    fn vector_vtable_decode(_decoder: &Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_len_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_value_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+}
+
+impl ComponentEncode for str {
+    type WorkingValue = Option<(u32, u32)>;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        if !self.is_empty() {
+            let value_offset = encoder.encode_i32(0)?;
+            Ok(Some((table_start, value_offset)))
+        }
+        else {
+            Ok(None)
+        }
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        match working_value {
+            Some((table_start, value_offset)) => {
+                encoder.encode_u16((value_offset - table_start) as u16)?;
+                Ok(())
+            }
+            None => {
+                encoder.encode_u16(0)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        if let Some((_table_start, value_offset)) = working_value {
+            let string_start = encoder.encode_str(self)?;
+            encoder.encode_i32_at(*value_offset, (string_start - value_offset) as i32)?;
+        }
+        Ok(())
+    }
+
+    fn vector_value_encode(&self, encoder: &mut Encoder, vector_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        let value_offset = encoder.encode_i32(0)?;
+        Ok(Some((vector_start, value_offset)))
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
+}
+
+impl <'a: 'b, 'b> ComponentDecode<'a> for &'b str {
+    type WorkingValue = (u32, u16);
+    type VectorWorkingValue = u32;
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+        Ok(((table_start, vtable_value), vtable_entry+2))
+    }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        if working_value.1 == 0 {
+            Ok("")
+        }
+        else {
+            let string_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+            decoder.decode_str(string_offset)
+        }
+    }
+
+    fn vector_vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+        let vector_offset = decoder.decode_offset(table_start + vtable_value as u32)?;
+        Ok((vector_offset, vtable_entry+2))
+    }
+
+    fn vector_len_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        Ok(decoder.decode_u32(*working_value)? as usize)
+    }
+
+    fn vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> {
+        // Every element is an offset to the string itself
+        let string_offset = decoder.decode_offset(decoder.vector_element(*working_value, idx, 4)?)?;
+        decoder.decode_str(string_offset)
+    }
+}
+
+// Truncate to capacity without splitting a character
+#[cfg(any(feature = "alloc", feature = "heapless", feature = "arrayvec"))]
+pub(crate) fn truncated_string<S: for<'s> TryFrom<&'s str>>(value: &str, capacity: usize) -> Result<S, DecodeError> {
+    let mut end = value.len().min(capacity);
+    while !value.is_char_boundary(end) {
+        end -= 1;
+    }
+    S::try_from(&value[..end]).map_err(|_| DecodeError::CollectionOverflow)
+}
+
+// String containers are all written and decoded through &str
+#[cfg(any(feature = "alloc", feature = "heapless", feature = "arrayvec"))]
+macro_rules! string_component_impls {
+    ($([$($generics:tt)*] $ty:ty, $capacity:expr);* $(;)?) => {$(
+        impl <$($generics)*> $crate::ComponentEncode for $ty {
+            type WorkingValue = Option<(u32, u32)>;
+
+            fn value_encode(&self, encoder: &mut $crate::Encoder, table_start: u32) -> Result<Self::WorkingValue, $crate::EncodeError> {
+                self.as_str().value_encode(encoder, table_start)
+            }
+
+            fn vtable_encode(&self, encoder: &mut $crate::Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), $crate::EncodeError> {
+                self.as_str().vtable_encode(encoder, vtable_start, working_value)
+            }
+
+            fn post_encode(&self, encoder: &mut $crate::Encoder, working_value: &Self::WorkingValue) -> Result<(), $crate::EncodeError> {
+                self.as_str().post_encode(encoder, working_value)
+            }
+
+            fn vector_value_encode(&self, encoder: &mut $crate::Encoder, vector_start: u32) -> Result<Self::WorkingValue, $crate::EncodeError> {
+                self.as_str().vector_value_encode(encoder, vector_start)
+            }
+
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                self.as_str().vector_working_value(vector_start, value_offset)
+            }
+        }
+
+        impl <'a, $($generics)*> $crate::ComponentDecode<'a> for $ty {
+            type WorkingValue = <&'a str as $crate::ComponentDecode<'a>>::WorkingValue;
+            type VectorWorkingValue = <&'a str as $crate::ComponentDecode<'a>>::VectorWorkingValue;
+
+            fn vtable_decode(decoder: &$crate::Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), $crate::DecodeError> {
+                <&str as $crate::ComponentDecode>::vtable_decode(decoder, table_start, vtable_entry)
+            }
+
+            fn value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, $crate::DecodeError> {
+                $crate::components::truncated_string(<&str as $crate::ComponentDecode>::value_decode(decoder, working_value)?, $capacity)
+            }
+
+            fn vector_vtable_decode(decoder: &$crate::Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), $crate::DecodeError> {
+                <&str as $crate::ComponentDecode>::vector_vtable_decode(decoder, table_start, vtable_entry)
+            }
+
+            fn vector_len_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, $crate::DecodeError> {
+                <&str as $crate::ComponentDecode>::vector_len_decode(decoder, working_value)
+            }
+
+            fn vector_value_decode(decoder: &$crate::Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, $crate::DecodeError> {
+                $crate::components::truncated_string(<&str as $crate::ComponentDecode>::vector_value_decode(decoder, working_value, idx)?, $capacity)
+            }
+        }
+    )*};
+}
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+pub(crate) use string_component_impls;
+
+#[cfg(feature = "alloc")]
+string_component_impls!([] alloc::string::String, usize::MAX);
+
+impl <T: ComponentEncode> ComponentEncode for [T] {
+    type WorkingValue = Option<(u32, u32)>;
+    const VECTOR_ELEMENT: bool = false;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        vector_offsets_encode::<T>(self.len(), encoder, table_start)
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_vtable_encode::<T>(encoder, working_value)
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_post_encode(self.iter(), encoder, working_value)
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
+}
+
+// Only bytes are stored as-is, so they're the only vector that can be borrowed as a slice. Slices of anything else
+// are encode only, Vector reads other elements in place
+impl <'a: 'b, 'b> ComponentDecode<'a> for &'b [u8] {
+    type WorkingValue = (u32, u16);
+    type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+        Ok(((table_start, vtable_value), vtable_entry + 2))
+    }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        if working_value.1 == 0 {
+            Ok(&[])
+        }
+        else {
+            let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+            let vector_len = decoder.decode_u32(vector_offset)?;
+            decoder.decode_bytes(vector_offset + 4, vector_len)
+        }
+    }
+
+    fn vector_vtable_decode(_decoder: &Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_len_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_value_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, DecodeError>
+    where
+        Self: Sized
+    {
+        Err(DecodeError::InvalidData)
+    }
+}
+
+// An address of either family is a byte vector of its 4 or 16 octets, [ubyte] in the schema
+impl ComponentEncode for IpAddr {
+    type WorkingValue = Option<(u32, u32)>;
+    // Its own [ubyte], so a vector of them would be a vector of vectors. Vectors of Ipv4Addr or Ipv6Addr are fine
+    const VECTOR_ELEMENT: bool = false;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        vector_offsets_encode::<u8>(1, encoder, table_start)
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_vtable_encode::<u8>(encoder, working_value)
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        match self {
+            IpAddr::V4(address) => vector_post_encode(address.octets().iter(), encoder, working_value),
+            IpAddr::V6(address) => vector_post_encode(address.octets().iter(), encoder, working_value)
+        }
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
+}
+
+impl <'a> ComponentDecode<'a> for IpAddr {
+    type WorkingValue = (u32, u16);
+    type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        <&[u8] as ComponentDecode>::vtable_decode(decoder, table_start, vtable_entry)
+    }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        // There's no address to fall back on when it's absent, that needs an Option
+        if working_value.1 == 0 {
+            return Err(DecodeError::InvalidData);
+        }
+        let octets = <&[u8] as ComponentDecode>::value_decode(decoder, working_value)?;
+        if let Ok(octets) = <[u8; 4]>::try_from(octets) {
+            Ok(IpAddr::from(octets))
+        }
+        else if let Ok(octets) = <[u8; 16]>::try_from(octets) {
+            Ok(IpAddr::from(octets))
+        }
+        else {
+            Err(DecodeError::InvalidValue)
+        }
+    }
+
+    fn vector_vtable_decode(_decoder: &Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_len_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_value_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+}
+
+// A vector written straight from an iterator, e.g. one that computes the elements on the fly, without collecting it
+// first. The iterator is cloned for every pass over the elements, so it has to yield the same ones each time. There's
+// nothing to decode the elements into, so tables holding one are #[femto(encode_only)]
+#[derive(Clone)]
+pub struct VectorIter<I> {
+    iter: I
+}
+
+impl <I> VectorIter<I> {
+    pub fn new(iter: I) -> Self {
+        Self {iter}
+    }
+}
+
+impl <I: ExactSizeIterator<Item: ComponentEncode> + Clone> ComponentEncode for VectorIter<I> {
+    type WorkingValue = Option<(u32, u32)>;
+    const VECTOR_ELEMENT: bool = false;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        vector_offsets_encode::<I::Item>(self.iter.len(), encoder, table_start)
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_vtable_encode::<I::Item>(encoder, working_value)
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        vector_post_encode(self.iter.clone(), encoder, working_value)
+    }
+
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
+}
+
+macro_rules! forward_component_encode {
+    ($([$($generics:tt)*] $ty:ty),* $(,)?) => {$(
+        impl <$($generics)*> ComponentEncode for $ty {
+            type WorkingValue = T::WorkingValue;
+            const VECTOR_ELEMENT: bool = T::VECTOR_ELEMENT;
+            fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+                (**self).value_encode(encoder, table_start)
+            }
+            fn vtable_encode(&self, encoder: &mut Encoder, vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+                (**self).vtable_encode(encoder, vtable_start, working_value)
+            }
+            fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+                (**self).post_encode(encoder, working_value)
+            }
+            fn vector_value_encode(&self, encoder: &mut Encoder, vector_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+                (**self).vector_value_encode(encoder, vector_start)
+            }
+            fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+                (**self).vector_working_value(vector_start, value_offset)
+            }
+            fn vtable_entries() -> u32 {
+                T::vtable_entries()
+            }
+            fn vector_vtable_entries() -> u32 {
+                T::vector_vtable_entries()
+            }
+            fn vector_type_encode(&self, encoder: &mut Encoder) -> Result<(), EncodeError> {
+                (**self).vector_type_encode(encoder)
+            }
+            fn union_value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+                (**self).union_value_encode(encoder, table_start)
+            }
+            fn union_post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+                (**self).union_post_encode(encoder, working_value)
+            }
+            fn vector_element_alignment() -> usize {
+                T::vector_element_alignment()
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! forward_component_decode {
+    ($($ty:ident),* $(,)?) => {$(
+        impl <'a, T: ComponentDecode<'a>> ComponentDecode<'a> for $ty<T> {
+            type WorkingValue = T::WorkingValue;
+            type VectorWorkingValue = T::VectorWorkingValue;
+            fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+                T::vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+                Ok($ty::new(T::value_decode(decoder, working_value)?))
+            }
+            fn vector_vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+                T::vector_vtable_decode(decoder, table_start, vtable_entry)
+            }
+            fn vector_len_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+                T::vector_len_decode(decoder, working_value)
+            }
+            fn vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> {
+                Ok($ty::new(T::vector_value_decode(decoder, working_value, idx)?))
+            }
+            fn vtable_entries() -> u32 {
+                T::vtable_entries()
+            }
+            fn vector_vtable_entries() -> u32 {
+                T::vector_vtable_entries()
+            }
+            fn is_absent(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<bool, DecodeError> {
+                T::is_absent(decoder, table_start, vtable_entry)
+            }
+            fn union_value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+                Ok($ty::new(T::union_value_decode(decoder, working_value)?))
+            }
+            fn union_vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> {
+                Ok($ty::new(T::union_vector_value_decode(decoder, working_value, idx)?))
+            }
+        }
+    )*};
+}
+
+forward_component_encode!([T: ComponentEncode + ?Sized] &T);
+
+#[cfg(feature = "alloc")]
+forward_component_encode!(
+    [T: ComponentEncode + ?Sized] alloc::boxed::Box<T>,
+    [T: ComponentEncode + ?Sized] alloc::rc::Rc<T>,
+    [T: ComponentEncode + alloc::borrow::ToOwned + ?Sized] alloc::borrow::Cow<'_, T>
+);
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+forward_component_encode!([T: ComponentEncode + ?Sized] alloc::sync::Arc<T>);
+
+#[cfg(feature = "alloc")]
+forward_component_decode!(Box, Rc);
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+forward_component_decode!(Arc);
+
+// Cow borrows from the buffer wherever the borrowed form can be decoded, e.g. Cow<str> and Cow<[u8]>
+#[cfg(feature = "alloc")]
+impl <'a: 'b, 'b, T: alloc::borrow::ToOwned + ?Sized> ComponentDecode<'a> for alloc::borrow::Cow<'b, T>
+where
+    &'b T: ComponentDecode<'a>
+{
+    type WorkingValue = <&'b T as ComponentDecode<'a>>::WorkingValue;
+    type VectorWorkingValue = <&'b T as ComponentDecode<'a>>::VectorWorkingValue;
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        <&'b T>::vtable_decode(decoder, table_start, vtable_entry)
+    }
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        Ok(alloc::borrow::Cow::Borrowed(<&'b T>::value_decode(decoder, working_value)?))
+    }
+    fn vector_vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        <&'b T>::vector_vtable_decode(decoder, table_start, vtable_entry)
+    }
+    fn vector_len_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        <&'b T>::vector_len_decode(decoder, working_value)
+    }
+    fn vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> {
+        Ok(alloc::borrow::Cow::Borrowed(<&'b T>::vector_value_decode(decoder, working_value, idx)?))
+    }
+}
+
+// A vector of primitives borrowed from the decoded buffer, elements are decoded on access
+#[derive(Clone, Copy)]
+pub struct Vector<'a, T> {
+    decoder: Decoder<'a>,
+    elements_start: u32,
+    len: u32,
+    phantom: PhantomData<T>
+}
+
+impl <'a, T: PrimitiveComponent> Vector<'a, T> {
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, idx: usize) -> Result<T, DecodeError> {
+        if idx >= self.len() {
+            return Err(DecodeError::InvalidData);
+        }
+        T::do_decode(&self.decoder, self.elements_start + (idx*T::size()) as u32)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, DecodeError>> + '_ {
+        (0..self.len()).map(|idx| self.get(idx))
+    }
+}
+
+impl <T> Default for Vector<'_, T> {
+    fn default() -> Self {
+        Self {decoder: Decoder::new(&[]), elements_start: 0, len: 0, phantom: PhantomData}
+    }
+}
+
+impl <T: PrimitiveComponent + core::fmt::Debug> core::fmt::Debug for Vector<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl <T: PrimitiveComponent> ComponentEncode for Vector<'_, T> {
+    type WorkingValue = Option<(u32, u32)>;
+    const VECTOR_ELEMENT: bool = false;
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        if !self.is_empty() {
+            let value_offset = encoder.encode_i32(0)?;
+            Ok(Some((table_start, value_offset)))
+        }
+        else {
+            Ok(None)
+        }
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        match working_value {
+            Some((table_start, value_offset)) => {
+                encoder.encode_u16((value_offset - table_start) as u16)?;
+                Ok(())
+            }
+            None => {
+                encoder.encode_u16(0)?;
+                Ok(())
+            }
+        }
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        if let Some((_table_start, value_offset)) = working_value {
+            let vector_start = encoder.encode_vector_len(self.len, T::alignment())?;
+            // The elements are already little endian with the right stride, copy them over as-is
+            let elements = self.decoder.decode_bytes(self.elements_start, self.len * T::size() as u32)
+                .map_err(|_| EncodeError::InvalidStructure)?;
+            encoder.encode_bytes(elements)?;
+            encoder.encode_i32_at(*value_offset, (vector_start - value_offset) as i32)?;
+        }
+        Ok(())
+    }
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        Some((vector_start, value_offset))
+    }
+}
+
+impl <'a: 'b, 'b, T: PrimitiveComponent> ComponentDecode<'a> for Vector<'b, T> {
+    type WorkingValue = (u32, u16);
+    type VectorWorkingValue = (); // Nested vectors are not supported by flatbuffers
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+        Ok(((table_start, vtable_value), vtable_entry+2))
+    }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        if working_value.1 == 0 {
+            Ok(Vector::default())
+        }
+        else {
+            let vector_offset = decoder.decode_offset(working_value.0 + working_value.1 as u32)?;
+            let vector_len = decoder.decode_u32(vector_offset)?;
+            let elements_size = vector_len.checked_mul(T::size() as u32).ok_or(DecodeError::InvalidData)?;
+            // Bounds check the whole vector up front
+            decoder.decode_bytes(vector_offset + 4, elements_size)?;
+            Ok(Vector {
+                decoder: *decoder,
+                elements_start: vector_offset + 4,
+                len: vector_len,
+                phantom: PhantomData
+            })
+        }
+    }
+
+    fn vector_vtable_decode(_decoder: &Decoder<'a>, _table_start: u32, _vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_len_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        Err(DecodeError::InvalidData)
+    }
+
+    fn vector_value_decode(_decoder: &Decoder<'a>, _working_value: &Self::VectorWorkingValue, _idx: usize) -> Result<Self, DecodeError>
+    where
+        Self: Sized
+    {
+        Err(DecodeError::InvalidData)
+    }
+}
+
+// An unknown union member. Without its schema it could be a table, struct or string, so what it refers to is found
+// by following whatever looks like an offset, see raw_walk. bytes() is the part of the buffer it was decoded from
+// holding it and everything it reaches apart from vtables, which can be shared and so be anywhere, with the member
+// offset() bytes into it. It's None for a struct, whose size can't be known, which then can't be encoded again
+#[derive(Clone, Copy)]
+pub struct RawTable<'a> {
+    buffer: &'a [u8],
+    position: u32,
+    extent: Option<(u32, u32)>
+}
+
+impl <'a> RawTable<'a> {
+    pub fn bytes(&self) -> Option<&'a [u8]> {
+        self.extent.map(|(start, end)| &self.buffer[start as usize..end as usize])
+    }
+
+    pub fn offset(&self) -> u32 {
+        self.extent.map_or(0, |(start, _)| self.position - start)
+    }
+
+    fn decode_at<'b: 'a>(decoder: &Decoder<'b>, position: u32) -> Self {
+        let buffer = decoder.buffer;
+        let (mut start, mut end) = (usize::MAX, 0);
+        let mut remaining = buffer.len() / 4 + 1;
+        let walked = raw_walk(buffer, position as usize, decoder.max_depth, &mut remaining, true, &mut |object| {
+            let (object_start, object_end) = match object {
+                RawObject::Table {position, size, ..} => (position, position + size),
+                RawObject::Other {start, end} => (start, end)
+            };
+            start = start.min(object_start);
+            end = end.max(object_end);
+            Some(())
+        });
+        RawTable {buffer, position, extent: walked.map(|_| (start as u32, end as u32))}
+    }
+}
+
+enum RawObject {
+    // The vtable isn't part of the table's size
+    Table {position: usize, size: usize, vtable: usize, vtable_size: usize},
+    Other {start: usize, end: usize}
+}
+
+fn raw_u16(buffer: &[u8], position: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(buffer.get(position..position.checked_add(2)?)?.try_into().ok()?))
+}
+
+fn raw_u32(buffer: &[u8], position: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(buffer.get(position..position.checked_add(4)?)?.try_into().ok()?))
+}
+
+// Where the offset at position points, if it could be one
+fn raw_target(buffer: &[u8], position: usize) -> Option<usize> {
+    let offset = raw_u32(buffer, position)?;
+    if !position.is_multiple_of(4) || offset == 0 || !offset.is_multiple_of(4) {
+        return None;
+    }
+    position.checked_add(offset as usize).filter(|target| target + 4 <= buffer.len())
+}
+
+// The vtable, vtable size and table size of a table at position, if it looks like one
+fn raw_table(buffer: &[u8], position: usize) -> Option<(usize, usize, usize)> {
+    if !position.is_multiple_of(4) {
+        return None;
+    }
+    let vtable = usize::try_from(position as i64 - raw_u32(buffer, position)? as i32 as i64).ok()?;
+    let vtable_size = raw_u16(buffer, vtable)? as usize;
+    let table_size = raw_u16(buffer, vtable + 2)? as usize;
+    if !vtable.is_multiple_of(2) || vtable_size < 4 || !vtable_size.is_multiple_of(2) || vtable + vtable_size > buffer.len()
+        || table_size < 4 || position + table_size > buffer.len()
+        || (vtable < position + table_size && position < vtable + vtable_size)
+    {
+        return None;
+    }
+    for entry in (vtable + 4..vtable + vtable_size).step_by(2) {
+        let field = raw_u16(buffer, entry)? as usize;
+        if field != 0 && (field < 4 || field >= table_size) {
+            return None;
+        }
+    }
+    Some((vtable, vtable_size, table_size))
+}
+
+// The end of a string at position, if it looks like one
+fn raw_str(buffer: &[u8], position: usize) -> Option<usize> {
+    let end = (position + 4).checked_add(raw_u32(buffer, position)? as usize)?;
+    let value = buffer.get(position + 4..end)?;
+    if buffer.get(end) != Some(&0) || value.contains(&0) || core::str::from_utf8(value).is_err() {
+        return None;
+    }
+    Some(end + 1)
+}
+
+// Visits whatever is at position and anything it refers to. Shared tables are visited once for every offset to
+// them, so remaining limits how many objects it looks at. Returns None if it had to give up, or if the member at
+// position isn't a table or string
+fn raw_walk(buffer: &[u8], position: usize, depth: u32, remaining: &mut usize, member: bool, visit: &mut impl FnMut(RawObject) -> Option<()>) -> Option<()> {
+    if depth == 0 || *remaining == 0 {
+        return None;
+    }
+    *remaining -= 1;
+    if let Some((vtable, vtable_size, table_size)) = raw_table(buffer, position) {
+        visit(RawObject::Table {position, size: table_size, vtable, vtable_size})?;
+        let fields = || (vtable + 4..vtable + vtable_size).step_by(2).map(|entry| raw_u16(buffer, entry).unwrap_or(0) as usize);
+        for field in fields().filter(|field| *field != 0) {
+            // Anything that could be an offset runs at least up to the next field
+            let next = fields().filter(|next| *next > field).min().unwrap_or(table_size);
+            if next - field < 4 {
+                continue;
+            }
+            // Nothing overlaps, so a small value pointing back into the table or its vtable isn't an offset
+            if let Some(target) = raw_target(buffer, position + field)
+                && !(position..position + table_size).contains(&target)
+                && !(vtable..vtable + vtable_size).contains(&target)
+            {
+                raw_walk(buffer, target, depth - 1, remaining, false, visit)?;
+            }
+        }
+        return Some(());
+    }
+    if let Some(end) = raw_str(buffer, position) {
+        return visit(RawObject::Other {start: position, end});
+    }
+    let len = raw_u32(buffer, position).map(|len| len as usize);
+    let end = len.and_then(|len| len.checked_mul(4)?.checked_add(position + 4)).filter(|end| *end <= buffer.len());
+    if let (Some(len), Some(end)) = (len, end)
+        && len > 0
+        && (0..len).all(|idx| raw_target(buffer, position + 4 + idx * 4)
+            .is_some_and(|target| raw_table(buffer, target).is_some() || raw_str(buffer, target).is_some()))
+    {
+        visit(RawObject::Other {start: position, end})?;
+        for idx in 0..len {
+            let target = raw_target(buffer, position + 4 + idx * 4)?;
+            raw_walk(buffer, target, depth - 1, remaining, false, visit)?;
+        }
+        return Some(());
+    }
+    if member {
+        return None;
+    }
+    // Vectors of anything else are taken to hold bytes, anything past that is only kept if something found comes
+    // after it. If it doesn't fit it wasn't an offset
+    match len.and_then(|len| (position + 4).checked_add(len)).filter(|end| *end <= buffer.len()) {
+        Some(end) => visit(RawObject::Other {start: position, end}),
+        None => Some(())
+    }
+}
+
+impl core::fmt::Debug for RawTable<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RawTable").field("offset", &self.offset()).field("len", &self.bytes().map(<[u8]>::len)).finish()
+    }
+}
+
+impl ComponentEncode for RawTable<'_> {
+    type WorkingValue = (u32, u32);
+
+    fn value_encode(&self, encoder: &mut Encoder, table_start: u32) -> Result<Self::WorkingValue, EncodeError> {
+        let value_offset = encoder.encode_i32(0)?;
+        Ok((table_start, value_offset))
+    }
+
+    fn vtable_encode(&self, encoder: &mut Encoder, _vtable_start: u32, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        encoder.encode_u16((working_value.1 - working_value.0) as u16)?;
+        Ok(())
+    }
+
+    fn post_encode(&self, encoder: &mut Encoder, working_value: &Self::WorkingValue) -> Result<(), EncodeError> {
+        let (start, end) = self.extent.ok_or(EncodeError::InvalidStructure)?;
+        let copy_start = encoder.encode_raw(&self.buffer[start as usize..end as usize], start)?;
+        encoder.encode_i32_at(working_value.1, (copy_start + self.position - start - working_value.1) as i32)?;
+        // Every table gets its own copy of its vtable after everything else, walking it again the same way as when
+        // it was decoded
+        let mut result = Ok(());
+        let mut remaining = self.buffer.len() / 4 + 1;
+        raw_walk(self.buffer, self.position as usize, u32::MAX, &mut remaining, true, &mut |object| {
+            if let RawObject::Table {position, vtable, vtable_size, ..} = object {
+                let table = copy_start + (position as u32 - start);
+                result = encoder.pad_to_align(2)
+                    .and_then(|_| encoder.encode_bytes(&self.buffer[vtable..vtable + vtable_size]))
+                    .and_then(|vtable| encoder.encode_i32_at(table, table as i32 - vtable as i32));
+            }
+            result.is_ok().then_some(())
+        });
+        result
+    }
+    fn vector_working_value(&self, vector_start: u32, value_offset: u32) -> Self::WorkingValue {
+        (vector_start, value_offset)
+    }
+}
+
+impl <'a: 'b, 'b> ComponentDecode<'a> for RawTable<'b> {
+    type WorkingValue = (u32, u16);
+    type VectorWorkingValue = u32;
+
+    fn vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::WorkingValue, u32), DecodeError> {
+        let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+        Ok(((table_start, vtable_value), vtable_entry+2))
+    }
+
+    fn value_decode(decoder: &Decoder<'a>, working_value: &Self::WorkingValue) -> Result<Self, DecodeError> {
+        if working_value.1 == 0 {
+            return Err(DecodeError::InvalidData);
+        }
+        Ok(Self::decode_at(decoder, decoder.decode_offset(working_value.0 + working_value.1 as u32)?))
+    }
+
+    fn vector_vtable_decode(decoder: &Decoder<'a>, table_start: u32, vtable_entry: u32) -> Result<(Self::VectorWorkingValue, u32), DecodeError> {
+        let vtable_value = decoder.decode_vtable_entry(vtable_entry)?;
+        let vector_offset = decoder.decode_offset(table_start + vtable_value as u32)?;
+        Ok((vector_offset, vtable_entry+2))
+    }
+
+    fn vector_len_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue) -> Result<usize, DecodeError> {
+        Ok(decoder.decode_u32(*working_value)? as usize)
+    }
+
+    fn vector_value_decode(decoder: &Decoder<'a>, working_value: &Self::VectorWorkingValue, idx: usize) -> Result<Self, DecodeError> {
+        Ok(Self::decode_at(decoder, decoder.decode_offset(decoder.vector_element(*working_value, idx, 4)?)?))
+    }
+}
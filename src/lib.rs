@@ -2,22 +2,43 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "heapless")]
 mod heapless_components;
+#[cfg(feature = "arrayvec")]
+mod arrayvec_components;
+#[cfg(feature = "smallvec")]
+mod smallvec_components;
+#[cfg(feature = "tinyvec")]
+mod tinyvec_components;
+#[cfg(feature = "bytes")]
+mod bytes_components;
+#[cfg(feature = "uuid")]
+mod uuid_components;
+#[cfg(feature = "chrono")]
+mod chrono_components;
+#[cfg(feature = "time")]
+mod time_components;
 
 pub mod table;
 pub mod components;
 
-pub use components::{ComponentEncode, ComponentDecode};
-pub use femtoflatbuffers_derive::{Table, Union};
+pub use components::{ComponentEncode, ComponentDecode, RawTable, Vector, VectorIter};
+pub use femtoflatbuffers_derive::{BitFlags, Enum, Struct, Table, Union};
+
+#[cfg(feature = "bitflags")]
+pub use bitflags;
 
 #[derive(thiserror::Error, Debug)]
 pub enum EncodeError {
     #[error("Not enough space in buffer")]
     OutOfSpace,
     #[error("Invalid structure")]
-    InvalidStructure
+    InvalidStructure,
+    #[error("Value out of range for its wire format")]
+    InvalidValue
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -27,7 +48,19 @@ pub enum DecodeError {
     #[error("Unsupported Feature")]
     UnsupportedFeature,
     #[error("Collection Overflow")]
-    CollectionOverflow
+    CollectionOverflow,
+    #[error("Invalid UTF-8")]
+    InvalidUtf8,
+    #[error("Unknown enum discriminant")]
+    UnknownDiscriminant,
+    #[error("Unknown flag bits")]
+    UnknownFlags,
+    #[error("Maximum nesting depth exceeded")]
+    DepthLimitExceeded,
+    #[error("Duplicate map key")]
+    DuplicateKey,
+    #[error("Value out of range for type")]
+    InvalidValue
 }
 
 pub struct Encoder<'a> {
@@ -43,7 +76,7 @@ impl<'a> Encoder<'a> {
         self.used_bytes as u32
     }
     pub fn done(self) -> &'a [u8] {
-        &self.buffer[..self.used_bytes as usize]
+        &self.buffer[..self.used_bytes]
     }
 
     pub fn pad_to_align(&mut self, align: usize) -> Result<(), EncodeError> {
@@ -70,6 +103,10 @@ impl<'a> Encoder<'a> {
         self.encode_u64(value as u64)
     }
 
+    pub fn encode_f64(&mut self, value: f64) -> Result<u32, EncodeError> {
+        self.encode_u64(value.to_bits())
+    }
+
     pub fn encode_u32(&mut self, value: u32) -> Result<u32, EncodeError> {
         self.pad_to_align(4)?;
         if self.buffer.len() - self.used_bytes < 4 {
@@ -85,6 +122,10 @@ impl<'a> Encoder<'a> {
         self.encode_u32(value as u32)
     }
 
+    pub fn encode_f32(&mut self, value: f32) -> Result<u32, EncodeError> {
+        self.encode_u32(value.to_bits())
+    }
+
     pub fn encode_u32_at(&mut self, offset: u32, value: u32) -> Result<(), EncodeError> {
         self.buffer[offset as usize..offset as usize+4].copy_from_slice(&value.to_le_bytes());
         Ok(())
@@ -123,55 +164,152 @@ impl<'a> Encoder<'a> {
         self.used_bytes += 1;
         Ok(offset)
     }
+
+    pub fn encode_i8(&mut self, value: i8) -> Result<u32, EncodeError> {
+        self.encode_u8(value as u8)
+    }
+
+    pub fn encode_bool(&mut self, value: bool) -> Result<u32, EncodeError> {
+        self.encode_u8(value as u8)
+    }
+
+    pub fn encode_bytes(&mut self, value: &[u8]) -> Result<u32, EncodeError> {
+        if self.buffer.len() - self.used_bytes < value.len() {
+            return Err(EncodeError::OutOfSpace);
+        }
+        let offset = self.used_bytes as u32;
+        self.buffer[self.used_bytes..self.used_bytes+value.len()].copy_from_slice(value);
+        self.used_bytes += value.len();
+        Ok(offset)
+    }
+
+    // Copies bytes that were decoded from another buffer as they are, at the same position relative to an 8 byte
+    // alignment as they had there, so anything aligned inside them stays aligned
+    pub fn encode_raw(&mut self, value: &[u8], source_start: u32) -> Result<u32, EncodeError> {
+        let padding = (source_start as usize + 8 - self.used_bytes % 8) % 8;
+        if self.used_bytes + padding > self.buffer.len() {
+            return Err(EncodeError::OutOfSpace);
+        }
+        self.used_bytes += padding;
+        self.encode_bytes(value)
+    }
+
+    pub fn encode_str(&mut self, value: &str) -> Result<u32, EncodeError> {
+        let offset = self.encode_u32(value.len() as u32)?;
+        self.encode_bytes(value.as_bytes())?;
+        self.encode_u8(0)?;
+        Ok(offset)
+    }
+
+    pub fn encode_vector_len(&mut self, len: u32, element_alignment: usize) -> Result<u32, EncodeError> {
+        // The length prefix sits directly in front of the first element, so pad such that the elements end up aligned
+        let align = element_alignment.max(4);
+        let padding = (align - (self.used_bytes + 4) % align) % align;
+        if self.used_bytes + padding > self.buffer.len() {
+            return Err(EncodeError::OutOfSpace);
+        }
+        self.used_bytes += padding;
+        self.encode_u32(len)
+    }
 }
 
 
 
+// Same default as the flatbuffers verifier
+const DEFAULT_MAX_DEPTH: u32 = 64;
+
+#[derive(Clone, Copy)]
 pub struct Decoder<'a> {
     buffer: &'a [u8],
+    #[cfg(feature = "bytes")]
+    shared: Option<&'a bytes::Bytes>,
+    depth: u32,
+    max_depth: u32,
+    vtable_end: u32,
+    unknown_flags: bool
 }
 
 impl<'a> Decoder<'a> {
     pub fn new(buffer: &'a [u8]) -> Self {
-        Self {buffer}
+        Self::with_max_depth(buffer, DEFAULT_MAX_DEPTH)
     }
 
-    pub fn decode_u64(&self, offset: u32) -> Result<u64, DecodeError> {
-        if offset + 8 > self.buffer.len() as u32 {
-            Err(DecodeError::InvalidData)
+    pub fn with_max_depth(buffer: &'a [u8], max_depth: u32) -> Self {
+        Self {
+            buffer,
+            #[cfg(feature = "bytes")]
+            shared: None,
+            depth: 0,
+            max_depth,
+            vtable_end: u32::MAX,
+            unknown_flags: false
+        }
+    }
+
+    // Keeps hold of the Bytes the buffer came from, so byte vectors can be decoded as slices of it without copying
+    #[cfg(feature = "bytes")]
+    pub fn new_shared(buffer: &'a bytes::Bytes) -> Self {
+        Self {shared: Some(buffer), ..Self::new(buffer)}
+    }
+
+    // Flags types keep bits they don't know about rather than rejecting them, for everything this decodes. Types
+    // marked #[femto(unknown)] always keep them
+    pub fn with_unknown_flags(&self) -> Self {
+        Self {unknown_flags: true, ..*self}
+    }
+
+    pub fn keeps_unknown_flags(&self) -> bool {
+        self.unknown_flags
+    }
+
+    // Called when descending into a table, so recursive schemas can't exhaust the stack on a hostile buffer
+    pub fn nested(&self) -> Result<Self, DecodeError> {
+        if self.depth >= self.max_depth {
+            return Err(DecodeError::DepthLimitExceeded);
+        }
+        Ok(Self {depth: self.depth + 1, ..*self})
+    }
+
+    // Set once a table's vtable is known, so the fields after it can tell how many entries the writer knew about
+    pub fn with_vtable(&self, vtable_start: u32, vtable_size: u16) -> Self {
+        Self {vtable_end: vtable_start.saturating_add(vtable_size as u32), ..*self}
+    }
+
+    // Buffers written against an older schema have shorter vtables, the fields added since read as absent
+    pub fn decode_vtable_entry(&self, vtable_entry: u32) -> Result<u16, DecodeError> {
+        if vtable_entry as u64 + 2 > self.vtable_end as u64 {
+            Ok(0)
         } else {
-            Ok(u64::from_le_bytes(
-                self.buffer[offset as usize..offset as usize + 8].try_into().unwrap()
-            ))
+            self.decode_u16(vtable_entry)
         }
     }
 
+    pub fn decode_u64(&self, offset: u32) -> Result<u64, DecodeError> {
+        Ok(u64::from_le_bytes(self.decode_bytes(offset, 8)?.try_into().unwrap()))
+    }
+
     pub fn decode_i64(&self, offset: u32) -> Result<i64, DecodeError> {
         self.decode_u64(offset).map(|x| x as i64)
     }
 
+    pub fn decode_f64(&self, offset: u32) -> Result<f64, DecodeError> {
+        self.decode_u64(offset).map(f64::from_bits)
+    }
+
     pub fn decode_u32(&self, offset: u32) -> Result<u32, DecodeError> {
-        if offset + 4 > self.buffer.len() as u32 {
-            Err(DecodeError::InvalidData)
-        } else {
-            Ok(u32::from_le_bytes(
-                self.buffer[offset as usize..offset as usize + 4].try_into().unwrap()
-            ))
-        }
+        Ok(u32::from_le_bytes(self.decode_bytes(offset, 4)?.try_into().unwrap()))
     }
 
     pub fn decode_i32(&self, offset: u32) -> Result<i32, DecodeError> {
         self.decode_u32(offset).map(|x| x as i32)
     }
 
+    pub fn decode_f32(&self, offset: u32) -> Result<f32, DecodeError> {
+        self.decode_u32(offset).map(f32::from_bits)
+    }
+
     pub fn decode_u16(&self, offset: u32) -> Result<u16, DecodeError> {
-        if offset + 2 > self.buffer.len() as u32 {
-            Err(DecodeError::InvalidData)
-        } else {
-            Ok(u16::from_le_bytes(
-                self.buffer[offset as usize..offset as usize + 2].try_into().unwrap()
-            ))
-        }
+        Ok(u16::from_le_bytes(self.decode_bytes(offset, 2)?.try_into().unwrap()))
     }
 
     pub fn decode_i16(&self, offset: u32) -> Result<i16, DecodeError> {
@@ -179,10 +317,55 @@ impl<'a> Decoder<'a> {
     }
 
     pub fn decode_u8(&self, offset: u32) -> Result<u8, DecodeError> {
-        if offset + 1 > self.buffer.len() as u32 {
-            Err(DecodeError::InvalidData)
-        } else {
-            Ok(self.buffer[offset as usize])
+        self.buffer.get(offset as usize).copied().ok_or(DecodeError::InvalidData)
+    }
+
+    pub fn decode_i8(&self, offset: u32) -> Result<i8, DecodeError> {
+        self.decode_u8(offset).map(|x| x as i8)
+    }
+
+    pub fn decode_bool(&self, offset: u32) -> Result<bool, DecodeError> {
+        self.decode_u8(offset).map(|x| x != 0)
+    }
+
+    // Only ever lands inside the buffer, so the small fixed steps taken from there can't overflow
+    pub fn decode_offset(&self, offset: u32) -> Result<u32, DecodeError> {
+        let relative = self.decode_i32(offset)?;
+        offset.checked_add_signed(relative).filter(|&target| (target as usize) < self.buffer.len()).ok_or(DecodeError::InvalidData)
+    }
+
+    // A table points back at its vtable, subtracting rather than adding its offset
+    pub fn decode_vtable_start(&self, table_start: u32) -> Result<u32, DecodeError> {
+        let relative = self.decode_i32(table_start)?;
+        u32::try_from(table_start as i64 - relative as i64).ok().filter(|&target| (target as usize) < self.buffer.len()).ok_or(DecodeError::InvalidData)
+    }
+
+    // Where element idx of the vector starting at vector_offset begins, which a huge length can't push past u32
+    pub fn vector_element(&self, vector_offset: u32, idx: usize, element_size: usize) -> Result<u32, DecodeError> {
+        idx.checked_mul(element_size)
+            .and_then(|element_offset| element_offset.checked_add(vector_offset as usize + 4))
+            .and_then(|element_start| u32::try_from(element_start).ok())
+            .ok_or(DecodeError::InvalidData)
+    }
+
+    pub fn decode_bytes(&self, offset: u32, len: u32) -> Result<&'a [u8], DecodeError> {
+        let start = offset as usize;
+        let end = start.checked_add(len as usize).ok_or(DecodeError::InvalidData)?;
+        self.buffer.get(start..end).ok_or(DecodeError::InvalidData)
+    }
+
+    #[cfg(feature = "bytes")]
+    pub fn decode_shared_bytes(&self, offset: u32, len: u32) -> Result<bytes::Bytes, DecodeError> {
+        let bytes = self.decode_bytes(offset, len)?;
+        match self.shared {
+            Some(shared) => Ok(shared.slice_ref(bytes)),
+            None => Ok(bytes::Bytes::copy_from_slice(bytes))
         }
     }
+
+    pub fn decode_str(&self, offset: u32) -> Result<&'a str, DecodeError> {
+        let len = self.decode_u32(offset)?;
+        let bytes = self.decode_bytes(offset.checked_add(4).ok_or(DecodeError::InvalidData)?, len)?;
+        core::str::from_utf8(bytes).map_err(|_| DecodeError::InvalidUtf8)
+    }
 }
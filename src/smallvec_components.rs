@@ -0,0 +1,4 @@
+use crate::components::vector_component_impls;
+
+// Spilled vectors keep growing on the heap, so there is nothing to truncate
+vector_component_impls!([A: smallvec::Array] smallvec::SmallVec<A>, A::Item, usize::MAX);
@@ -1,6 +1,18 @@
 use crate::{Decoder, Encoder, EncodeError, DecodeError};
 
-pub trait Table {
+pub trait Table<'a> {
     fn encode(&self, encoder: &mut Encoder) -> Result<(), EncodeError>;
-    fn decode(decoder: &Decoder) -> Result<Self, DecodeError> where Self: Sized;
+    fn decode(decoder: &Decoder<'a>) -> Result<Self, DecodeError> where Self: Sized;
+}
+
+// Anything that can be written as a table, which every Table can. Tables holding fields that can only be written,
+// e.g. a VectorIter, are only this, derived with #[femto(encode_only)]
+pub trait TableEncode {
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), EncodeError>;
+}
+
+impl <'a, T: Table<'a>> TableEncode for T {
+    fn encode(&self, encoder: &mut Encoder) -> Result<(), EncodeError> {
+        Table::encode(self, encoder)
+    }
 }
\ No newline at end of file
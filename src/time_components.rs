@@ -0,0 +1,40 @@
+use time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+use crate::{DecodeError, Decoder, EncodeError, Encoder};
+use crate::components::{primitive_component_impls, PrimitiveComponent};
+
+// Timestamps are a long of nanoseconds since the Unix epoch, the same as the chrono impls. Offsets aren't kept, so
+// they decode as UTC, and primitive ones are taken as UTC. Only the years 1677 to 2262 can be encoded
+impl PrimitiveComponent for OffsetDateTime {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        encoder.encode_i64(self.unix_timestamp_nanos().try_into().map_err(|_| EncodeError::InvalidValue)?)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        OffsetDateTime::from_unix_timestamp_nanos(decoder.decode_i64(offset)?.into()).map_err(|_| DecodeError::InvalidValue)
+    }
+}
+
+impl PrimitiveComponent for PrimitiveDateTime {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.assume_utc().do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        OffsetDateTime::do_decode(decoder, offset).map(|x| PrimitiveDateTime::new(x.date(), x.time()))
+    }
+}
+
+// Durations are a long of nanoseconds, about 292 years either way
+impl PrimitiveComponent for Duration {
+    fn alignment() -> usize {8}
+    fn size() -> usize {8}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {
+        encoder.encode_i64(self.whole_nanoseconds().try_into().map_err(|_| EncodeError::InvalidValue)?)
+    }
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {
+        decoder.decode_i64(offset).map(Duration::nanoseconds)
+    }
+}
+
+primitive_component_impls!([] OffsetDateTime, [] PrimitiveDateTime, [] Duration);
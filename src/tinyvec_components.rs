@@ -0,0 +1,6 @@
+use crate::components::vector_component_impls;
+
+vector_component_impls!([A: tinyvec::Array] tinyvec::ArrayVec<A>, A::Item, A::CAPACITY);
+// Spilled vectors keep growing on the heap, so there is nothing to truncate
+#[cfg(feature = "alloc")]
+vector_component_impls!([A: tinyvec::Array] tinyvec::TinyVec<A>, A::Item, usize::MAX);
@@ -0,0 +1,14 @@
+use uuid::Uuid;
+
+use crate::{DecodeError, Decoder, EncodeError, Encoder};
+use crate::components::{primitive_component_impls, PrimitiveComponent};
+
+// Inline in RFC 9562 byte order, the same as Uuid::as_bytes: struct Uuid { bytes: [ubyte:16]; }
+impl PrimitiveComponent for Uuid {
+    fn alignment() -> usize {1}
+    fn size() -> usize {16}
+    fn do_encode(&self, encoder: &mut Encoder) -> Result<u32, EncodeError> {self.as_bytes().do_encode(encoder)}
+    fn do_decode(decoder: &Decoder, offset: u32) -> Result<Self, DecodeError> {<[u8; 16]>::do_decode(decoder, offset).map(Uuid::from_bytes)}
+}
+
+primitive_component_impls!([] Uuid);
@@ -0,0 +1,150 @@
+use femtoflatbuffers::{Decoder, Enum, Struct, Table, Vector};
+use femtoflatbuffers::components::PrimitiveComponent;
+use femtoflatbuffers::table::Table;
+
+#[derive(Enum, Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+enum Color {
+    Red,
+    Green,
+    Blue
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Calibration {
+    id: u16,
+    gains: [f32; 3],
+    colors: [Color; 2],
+    points: [Vec3; 2]
+}
+
+#[derive(Table, Debug)]
+struct ArrayTest<'a> {
+    a: Calibration,
+    b: Vector<'a, Calibration>,
+    c: i32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct InlineArrayTest {
+    a: [u16; 4],
+    b: [Vec3; 2],
+    c: i32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn calibration(id: u16) -> Calibration {
+    Calibration{
+        id,
+        gains: [0.5, 1.0, 1.5],
+        colors: [Color::Green, Color::Blue],
+        points: [Vec3{x: 1.0, y: 2.0, z: 3.0}, Vec3{x: -1.0, y: -2.0, z: -3.0}]
+    }
+}
+
+fn generated_calibration(id: u16) -> test::test::Calibration {
+    test::test::Calibration::new(
+        id,
+        &[0.5, 1.0, 1.5],
+        &[test::test::Color::Green, test::test::Color::Blue],
+        &[test::test::Vec3::new(1.0, 2.0, 3.0), test::test::Vec3::new(-1.0, -2.0, -3.0)]
+    )
+}
+
+fn build_array_test() -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let b = builder.create_vector(&[generated_calibration(2), generated_calibration(3)]);
+    let mut table_builder = test::test::ArrayTestBuilder::new(&mut builder);
+    table_builder.add_a(&generated_calibration(1));
+    table_builder.add_b(b);
+    table_builder.add_c(9);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn layout_test() {
+    assert_eq!(<[f32; 3]>::size(), 12);
+    assert_eq!(Calibration::alignment(), 4);
+    assert_eq!(Calibration::size(), size_of::<test::test::Calibration>());
+
+    let mut buffer = [0u8; 64];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    calibration(1).do_encode(&mut encoder).unwrap();
+    assert_eq!(encoder.done(), generated_calibration(1).0);
+}
+
+#[test]
+fn encode_test() {
+    let encoded_test = build_array_test();
+    let borrowed_test = ArrayTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    let test = ArrayTest{
+        a: calibration(4),
+        b: borrowed_test.b,
+        c: 10
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::ArrayTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    let a = decoded_test.a().unwrap();
+    assert_eq!(a.id(), 4);
+    assert_eq!(a.gains().iter().collect::<Vec<_>>(), vec![0.5, 1.0, 1.5]);
+    assert_eq!(a.colors().iter().collect::<Vec<_>>(), vec![test::test::Color::Green, test::test::Color::Blue]);
+    assert_eq!(a.points().get(1).z(), -3.0);
+    assert_eq!(decoded_test.b().unwrap().get(1).id(), 3);
+    assert_eq!(decoded_test.c(), 10);
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_array_test();
+    println!("{:x?}", encoded_test);
+    let decoded_test = ArrayTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a, calibration(1));
+    assert_eq!(decoded_test.b.iter().collect::<Result<Vec<_>, _>>().unwrap(), vec![calibration(2), calibration(3)]);
+    assert_eq!(decoded_test.c, 9);
+}
+
+#[test]
+fn decode_truncated_test() {
+    let buffer = generated_calibration(1).0;
+    assert_eq!(<[Calibration; 1]>::do_decode(&Decoder::new(&buffer), 0).unwrap(), [calibration(1)]);
+    assert!(<[Calibration; 1]>::do_decode(&Decoder::new(&buffer[..40]), 0).is_err());
+    assert!(<[f32; 3]>::do_decode(&Decoder::new(&buffer), 36).is_err());
+}
+
+#[test]
+fn inline_array_test() {
+    let test = InlineArrayTest{
+        a: [1, 2, 3, 4],
+        b: [Vec3{x: 1.0, y: 2.0, z: 3.0}, Vec3{x: 4.0, y: 5.0, z: 6.0}],
+        c: 7
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = InlineArrayTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
@@ -26,7 +26,7 @@ fn encode_test() {
     let encoded = encoder.done();
     println!("{:x?}", encoded);
 
-    let decoded_test = test::test::root_as_test(&encoded).unwrap();
+    let decoded_test = test::test::root_as_test(encoded).unwrap();
     println!("{:?}", decoded_test);
 }
 
@@ -43,6 +43,6 @@ fn decode_test() {
         builder.finished_data()
     };
     println!("{:x?}", encoded_test);
-    let decoded_test = Test::decode(&Decoder::new(&encoded_test)).unwrap();
+    let decoded_test = Test::decode(&Decoder::new(encoded_test)).unwrap();
     println!("{:?}", decoded_test);
 }
\ No newline at end of file
@@ -0,0 +1,105 @@
+use femtoflatbuffers::{Decoder, Table, Vector};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug)]
+struct BorrowedTest<'a> {
+    a: &'a str,
+    b: &'a [u8],
+    c: Vector<'a, u16>,
+    d: u32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn build_borrowed_test() -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = builder.create_string("zero copy");
+    let b = builder.create_vector(&[1u8, 2, 3, 4]);
+    let c = builder.create_vector(&[500u16, 600, 700]);
+    let mut table_builder = test::test::BorrowedTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    table_builder.add_b(b);
+    table_builder.add_c(c);
+    table_builder.add_d(7);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_borrowed_test();
+    println!("{:x?}", encoded_test);
+    let decoded_test = BorrowedTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+
+    assert_eq!(decoded_test.a, "zero copy");
+    assert_eq!(decoded_test.b, &[1, 2, 3, 4]);
+    assert_eq!(decoded_test.c.len(), 3);
+    assert_eq!(decoded_test.c.iter().collect::<Result<Vec<_>, _>>().unwrap(), vec![500, 600, 700]);
+    assert_eq!(decoded_test.d, 7);
+
+    // Borrowed fields point straight into the input buffer
+    let buffer_range = encoded_test.as_ptr_range();
+    assert!(buffer_range.contains(&decoded_test.a.as_ptr()));
+    assert!(buffer_range.contains(&decoded_test.b.as_ptr()));
+}
+
+#[test]
+fn encode_test() {
+    let test = BorrowedTest{
+        a: "zero copy",
+        b: &[1, 2, 3, 4],
+        c: Vector::default(),
+        d: 7
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::BorrowedTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), Some("zero copy"));
+    assert_eq!(decoded_test.b().unwrap().bytes(), &[1, 2, 3, 4]);
+    assert!(decoded_test.c().is_none());
+    assert_eq!(decoded_test.d(), 7);
+}
+
+#[test]
+fn reencode_test() {
+    // A decoded borrowed table can be written back out without copying into owned containers
+    let encoded_test = build_borrowed_test();
+    let decoded_test = BorrowedTest::decode(&Decoder::new(&encoded_test)).unwrap();
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+
+    let decoded_test = flatbuffers::root::<test::test::BorrowedTest>(encoded).unwrap();
+    assert_eq!(decoded_test.a(), Some("zero copy"));
+    assert_eq!(decoded_test.b().unwrap().bytes(), &[1, 2, 3, 4]);
+    assert_eq!(decoded_test.c().unwrap().iter().collect::<Vec<_>>(), vec![500, 600, 700]);
+    assert_eq!(decoded_test.d(), 7);
+}
+
+#[test]
+fn decode_absent_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let mut table_builder = test::test::BorrowedTestBuilder::new(&mut builder);
+    table_builder.add_d(7);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    let decoded_test = BorrowedTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert_eq!(decoded_test.a, "");
+    assert!(decoded_test.b.is_empty());
+    assert!(decoded_test.c.is_empty());
+    assert_eq!(decoded_test.d, 7);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{Bytes, BytesMut};
+use femtoflatbuffers::{Decoder, Table, Vector};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug)]
+struct BytesTest<'a> {
+    a: &'a str,
+    b: Bytes,
+    c: Vector<'a, u16>,
+    d: u32
+}
+
+#[derive(Table, Debug)]
+struct BytesMutTest<'a> {
+    a: &'a str,
+    b: BytesMut,
+    c: Vector<'a, u16>,
+    d: u32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn build_borrowed_test(b: Option<&[u8]>) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = builder.create_string("zero copy");
+    let b = b.map(|b| builder.create_vector(b));
+    let mut table_builder = test::test::BorrowedTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    if let Some(b) = b {
+        table_builder.add_b(b);
+    }
+    table_builder.add_d(7);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = Bytes::from(build_borrowed_test(Some(&[1, 2, 3, 4])));
+    let decoded_test = BytesTest::decode(&Decoder::new_shared(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a, "zero copy");
+    assert_eq!(decoded_test.b, &[1, 2, 3, 4][..]);
+    assert_eq!(decoded_test.d, 7);
+
+    // Sliced out of the input rather than copied
+    assert!(encoded_test.as_ptr_range().contains(&decoded_test.b.as_ptr()));
+
+    // A plain decoder has nothing to share, so the bytes are copied
+    let decoded_test = BytesTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.b, &[1, 2, 3, 4][..]);
+    assert!(!encoded_test.as_ptr_range().contains(&decoded_test.b.as_ptr()));
+
+    let decoded_test = BytesMutTest::decode(&Decoder::new_shared(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.b, &[1, 2, 3, 4][..]);
+
+    let encoded_test = Bytes::from(build_borrowed_test(None));
+    let decoded_test = BytesTest::decode(&Decoder::new_shared(&encoded_test)).unwrap();
+    assert!(decoded_test.b.is_empty());
+}
+
+#[test]
+fn encode_test() {
+    let test = BytesTest{a: "zero copy", b: Bytes::from_static(&[1, 2, 3, 4]), c: Vector::default(), d: 7};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    let decoded_test = flatbuffers::root::<test::test::BorrowedTest>(encoded).unwrap();
+    assert_eq!(decoded_test.b().unwrap().bytes(), &[1, 2, 3, 4]);
+    assert_eq!(decoded_test.d(), 7);
+
+    let test = BytesMutTest{a: "zero copy", b: BytesMut::from(&[5u8, 6][..]), c: Vector::default(), d: 7};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::BorrowedTest>(encoder.done()).unwrap();
+    assert_eq!(decoded_test.b().unwrap().bytes(), &[5, 6]);
+}
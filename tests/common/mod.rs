@@ -0,0 +1,62 @@
+// Shared between the integration tests, each of them only uses some of it
+#![allow(dead_code)]
+
+use femtoflatbuffers::table::TableEncode;
+
+#[allow(dead_code, unused_imports)]
+#[path = "../test_generated.rs"]
+pub mod test;
+
+pub fn encode(test: &impl TableEncode) -> Vec<u8> {
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    encoded.to_vec()
+}
+
+pub fn build_string_list_test(a: &[&str]) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    // Equivalent of the create_vector_of_strings helper that newer flatbuffers releases dropped
+    let a = a.iter().map(|x| builder.create_string(x)).collect::<Vec<_>>();
+    let a = builder.create_vector(&a);
+    let mut table_builder = test::test::StringListTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    table_builder.add_b(7);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+pub fn check_string_list_test(encoded: &[u8], a: &[&str]) {
+    let decoded_test = flatbuffers::root::<test::test::StringListTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a().unwrap().iter().collect::<Vec<_>>(), a);
+    assert_eq!(decoded_test.b(), 7);
+}
+
+// A StructTest holding the same values whether it was written from structs, slices or iterators
+pub fn check_struct_test(encoded: &[u8]) {
+    let decoded_test = flatbuffers::root::<test::test::StructTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    let a = decoded_test.a().unwrap();
+    assert_eq!((a.x(), a.y(), a.z()), (1.5, 2.5, 3.5));
+    let b = decoded_test.b().unwrap();
+    assert_eq!(b.id(), 7);
+    assert_eq!((b.position().x(), b.position().y(), b.position().z()), (-1.0, -2.0, -3.0));
+    assert_eq!(b.mass(), 1.0e10);
+    assert!(b.active());
+    let c = decoded_test.c().unwrap();
+    assert_eq!(c.len(), 2);
+    assert_eq!((c.get(1).x(), c.get(1).y(), c.get(1).z()), (4.0, 5.0, 6.0));
+    let d = decoded_test.d().unwrap();
+    assert_eq!(d.len(), 2);
+    assert_eq!(d.get(0).id(), 1);
+    assert_eq!(d.get(0).position().z(), 0.125);
+    assert!(d.get(0).active());
+    assert_eq!(d.get(1).id(), 2);
+    assert_eq!(d.get(1).mass(), 20.0);
+    assert!(!d.get(1).active());
+    assert_eq!(decoded_test.e(), 42);
+}
@@ -0,0 +1,170 @@
+#![cfg(any(feature = "arrayvec", feature = "smallvec", feature = "tinyvec"))]
+
+use femtoflatbuffers::{Decoder, Table};
+use femtoflatbuffers::table::Table;
+
+#[allow(dead_code)]
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: u32,
+    b: u32,
+    c: u32
+}
+
+#[cfg(feature = "arrayvec")]
+#[derive(Table, Debug, PartialEq)]
+struct ArrayVecListTest {
+    a: u32,
+    b: arrayvec::ArrayVec<Test, 2>
+}
+
+#[cfg(feature = "arrayvec")]
+#[derive(Table, Debug, PartialEq)]
+struct ArrayStringTest {
+    a: arrayvec::ArrayString<8>,
+    b: u32
+}
+
+#[cfg(feature = "arrayvec")]
+#[derive(Table, Debug, PartialEq)]
+struct ArrayStringListTest {
+    a: arrayvec::ArrayVec<arrayvec::ArrayString<8>, 2>,
+    b: i32
+}
+
+#[cfg(feature = "smallvec")]
+#[derive(Table, Debug, PartialEq)]
+struct SmallVecListTest {
+    a: u32,
+    b: smallvec::SmallVec<[Test; 2]>
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+#[derive(Table, Debug, PartialEq)]
+struct TinyVecStringListTest<'a> {
+    a: tinyvec::TinyVec<[&'a str; 2]>,
+    b: i32
+}
+
+#[cfg(feature = "tinyvec")]
+#[derive(Table, Debug, PartialEq)]
+struct TinyArrayVecStringListTest<'a> {
+    a: tinyvec::ArrayVec<[&'a str; 2]>,
+    b: i32
+}
+
+mod common;
+#[allow(unused_imports)]
+use common::{test, build_string_list_test, check_string_list_test};
+
+#[allow(dead_code)]
+fn test_value(x: u32) -> Test {
+    Test{a: x, b: x + 1, c: x + 2}
+}
+
+#[allow(dead_code)]
+fn build_list_test(b: &[u32]) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let b = b.iter().map(|x| test::test::Test::create(&mut builder, &test::test::TestArgs{a: *x as i32, b: *x as i32 + 1, c: *x as i32 + 2})).collect::<Vec<_>>();
+    let b = builder.create_vector(&b);
+    let mut table_builder = test::test::ListTestBuilder::new(&mut builder);
+    table_builder.add_a(5);
+    table_builder.add_b(b);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[allow(dead_code)]
+fn check_list_test(encoded: &[u8], b: &[u32]) {
+    let decoded_test = flatbuffers::root::<test::test::ListTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), 5);
+    assert_eq!(decoded_test.b().unwrap().iter().map(|x| x.a() as u32).collect::<Vec<_>>(), b);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn array_vec_test() {
+    let test = ArrayVecListTest{a: 5, b: [test_value(1), test_value(4)].into()};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    check_list_test(encoded, &[1, 4]);
+    let decoded_test = ArrayVecListTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+
+    // Longer vectors are truncated to capacity
+    let encoded_test = build_list_test(&[1, 4, 7]);
+    let decoded_test = ArrayVecListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn array_string_test() {
+    let test = ArrayStringTest{a: arrayvec::ArrayString::from("höla").unwrap(), b: 2};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    let decoded_test = flatbuffers::root::<test::test::StringTest>(encoded).unwrap();
+    assert_eq!(decoded_test.a(), Some("höla"));
+    let decoded_test = ArrayStringTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+
+    // Longer strings are truncated to capacity on a character boundary
+    let encoded_test = build_string_list_test(&["alpha", "abcdefgé", "gamma"]);
+    let decoded_test = ArrayStringListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a.iter().map(|x| x.as_str()).collect::<Vec<_>>(), vec!["alpha", "abcdefg"]);
+    assert_eq!(decoded_test.b, 7);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn small_vec_test() {
+    // Both inline and spilled onto the heap
+    for b in [&[1, 4][..], &[1, 4, 7, 10][..]] {
+        let test = SmallVecListTest{a: 5, b: b.iter().map(|x| test_value(*x)).collect()};
+
+        let mut buffer = [0u8; 1024];
+        let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+        test.encode(&mut encoder).unwrap();
+        let encoded = encoder.done();
+        check_list_test(encoded, b);
+        let decoded_test = SmallVecListTest::decode(&Decoder::new(encoded)).unwrap();
+        assert_eq!(decoded_test, test);
+    }
+
+    let encoded_test = build_list_test(&[]);
+    let decoded_test = SmallVecListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert!(decoded_test.b.is_empty());
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+#[test]
+fn tiny_vec_test() {
+    for a in [&["alpha", ""][..], &["alpha", "", "gamma δ"][..]] {
+        let test = TinyVecStringListTest{a: a.iter().copied().collect(), b: 7};
+
+        let mut buffer = [0u8; 1024];
+        let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+        test.encode(&mut encoder).unwrap();
+        let encoded = encoder.done();
+        check_string_list_test(encoded, a);
+        let decoded_test = TinyVecStringListTest::decode(&Decoder::new(encoded)).unwrap();
+        assert_eq!(decoded_test, test);
+    }
+}
+
+#[cfg(feature = "tinyvec")]
+#[test]
+fn tiny_array_vec_test() {
+    // The inline only variant truncates to capacity
+    let encoded_test = build_string_list_test(&["alpha", "", "gamma δ"]);
+    let decoded_test = TinyArrayVecStringListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a.as_slice(), &["alpha", ""]);
+}
@@ -0,0 +1,131 @@
+use femtoflatbuffers::{Decoder, Enum, Struct, Table, Vector};
+use femtoflatbuffers::table::Table;
+
+#[derive(Enum, Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+enum Color {
+    Red,
+    Green,
+    Blue
+}
+
+#[derive(Enum, Debug, PartialEq, Clone, Copy)]
+#[repr(i16)]
+enum Level {
+    Low = -1,
+    Mid,
+    High = 5
+}
+
+// Same wire type as Color, but keeps values it doesn't know about
+#[derive(Enum, Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+enum OpenColor {
+    Red,
+    Green,
+    #[femto(unknown)]
+    Unknown(u8)
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct ColoredPoint {
+    color: Color,
+    x: f32
+}
+
+#[derive(Table, Debug)]
+struct EnumTest<'a> {
+    a: Color,
+    b: Level,
+    c: Vector<'a, Color>,
+    d: ColoredPoint,
+    e: i32
+}
+
+#[derive(Table, Debug)]
+struct OpenEnumTest<'a> {
+    a: OpenColor,
+    b: Level,
+    c: Vector<'a, OpenColor>,
+    d: ColoredPoint,
+    e: i32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn build_enum_test(a: test::test::Color, c: &[test::test::Color]) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let c = builder.create_vector(c);
+    let mut table_builder = test::test::EnumTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    table_builder.add_b(test::test::Level::Low);
+    table_builder.add_c(c);
+    table_builder.add_d(&test::test::ColoredPoint::new(test::test::Color::Green, 0.5));
+    table_builder.add_e(3);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn encode_test() {
+    let encoded_test = build_enum_test(test::test::Color::Blue, &[test::test::Color::Green, test::test::Color::Blue]);
+    let borrowed_test = EnumTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    let test = EnumTest{
+        a: Color::Green,
+        b: Level::High,
+        c: borrowed_test.c,
+        d: ColoredPoint{color: Color::Blue, x: 1.5},
+        e: 4
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::EnumTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), test::test::Color::Green);
+    assert_eq!(decoded_test.b(), test::test::Level::High);
+    assert_eq!(decoded_test.c().unwrap().iter().collect::<Vec<_>>(), vec![test::test::Color::Green, test::test::Color::Blue]);
+    assert_eq!(decoded_test.d().unwrap().color(), test::test::Color::Blue);
+    assert_eq!(decoded_test.d().unwrap().x(), 1.5);
+    assert_eq!(decoded_test.e(), 4);
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_enum_test(test::test::Color::Blue, &[test::test::Color::Green, test::test::Color::Blue]);
+    println!("{:x?}", encoded_test);
+    let decoded_test = EnumTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a, Color::Blue);
+    assert_eq!(decoded_test.b, Level::Low);
+    assert_eq!(decoded_test.c.iter().collect::<Result<Vec<_>, _>>().unwrap(), vec![Color::Green, Color::Blue]);
+    assert_eq!(decoded_test.d, ColoredPoint{color: Color::Green, x: 0.5});
+    assert_eq!(decoded_test.e, 3);
+}
+
+#[test]
+fn decode_unknown_test() {
+    let encoded_test = build_enum_test(test::test::Color(7), &[test::test::Color::Green, test::test::Color(9)]);
+    let result = EnumTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(femtoflatbuffers::DecodeError::UnknownDiscriminant)));
+
+    let decoded_test = OpenEnumTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a, OpenColor::Unknown(7));
+    assert_eq!(decoded_test.c.get(0).unwrap(), OpenColor::Green);
+    assert_eq!(decoded_test.c.get(1).unwrap(), OpenColor::Unknown(9));
+
+    // Unknown values are written back unchanged
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::EnumTest>(encoder.done()).unwrap();
+    assert_eq!(decoded_test.a(), test::test::Color(7));
+    assert_eq!(decoded_test.c().unwrap().get(1), test::test::Color(9));
+}
@@ -0,0 +1,120 @@
+use core::num::NonZeroU32;
+
+use femtoflatbuffers::{Decoder, DecodeError, Struct, Table};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: i32,
+    b: i32,
+    c: i32
+}
+
+// What older firmware wrote, the string ends up right after the vtable
+#[derive(Table, Debug, PartialEq)]
+struct DeviceV1<'a> {
+    id: u32,
+    name: &'a str
+}
+
+// The same table after a few releases of additions
+#[derive(Table, Debug, PartialEq)]
+struct DeviceV2<'a> {
+    id: u32,
+    name: &'a str,
+    flags: u16,
+    serial: Option<&'a str>,
+    calibration: Option<Test>,
+    retries: Option<u8>,
+    label: &'a str
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct NonZeroDevice<'a> {
+    id: u32,
+    name: &'a str,
+    flags: NonZeroU32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct RequiredDevice<'a> {
+    id: u32,
+    name: &'a str,
+    calibration: Test
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct Ack(u32, u16);
+
+#[derive(Table, Debug, PartialEq)]
+struct V1 {
+    a: u32
+}
+
+#[derive(Struct, Debug, PartialEq)]
+struct Big {
+    m: [f32; 100]
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct V2 {
+    a: u32,
+    b: Big
+}
+
+mod common;
+use common::{test, encode};
+
+#[test]
+fn old_buffer_test() {
+    let encoded = encode(&DeviceV1{id: 7, name: "sensor"});
+
+    let decoded_test = DeviceV2::decode(&Decoder::new(&encoded)).unwrap();
+    assert_eq!(decoded_test, DeviceV2{id: 7, name: "sensor", flags: 0, serial: None, calibration: None, retries: None, label: ""});
+
+    // Fields the old schema didn't have can't hold a value nothing wrote
+    assert!(matches!(NonZeroDevice::decode(&Decoder::new(&encoded)), Err(DecodeError::InvalidValue)));
+    assert!(matches!(RequiredDevice::decode(&Decoder::new(&encoded)), Err(DecodeError::InvalidData)));
+}
+
+#[test]
+fn large_struct_test() {
+    // Absent structs are zeroed however large they are
+    let encoded = encode(&V1{a: 7});
+    assert_eq!(V2::decode(&Decoder::new(&encoded)).unwrap(), V2{a: 7, b: Big{m: [0.0; 100]}});
+}
+
+#[test]
+fn new_buffer_test() {
+    let test = DeviceV2{
+        id: 7,
+        name: "sensor",
+        flags: 3,
+        serial: Some("A-1"),
+        calibration: Some(Test{a: 1, b: 2, c: 3}),
+        retries: Some(2),
+        label: "lab"
+    };
+    let encoded = encode(&test);
+
+    assert_eq!(DeviceV2::decode(&Decoder::new(&encoded)).unwrap(), test);
+    // Older readers just don't look at the entries past their own
+    assert_eq!(DeviceV1::decode(&Decoder::new(&encoded)).unwrap(), DeviceV1{id: 7, name: "sensor"});
+}
+
+#[test]
+fn trimmed_vtable_test() {
+    // flatc leaves trailing fields that hold their default out of the vtable altogether
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let table = test::test::Ack::create(&mut builder, &test::test::AckArgs{seq: 7, window: 0});
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+    println!("{:x?}", encoded_test);
+    assert_eq!(Ack::decode(&Decoder::new(encoded_test)).unwrap(), Ack(7, 0));
+
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let table = test::test::Test::create(&mut builder, &test::test::TestArgs{a: 0, b: 0, c: 0});
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+    assert_eq!(Test::decode(&Decoder::new(encoded_test)).unwrap(), Test{a: 0, b: 0, c: 0});
+}
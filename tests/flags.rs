@@ -0,0 +1,109 @@
+#![cfg(feature = "bitflags")]
+
+use femtoflatbuffers::{BitFlags, Decoder, Table, Vector};
+use femtoflatbuffers::table::Table;
+
+femtoflatbuffers::bitflags::bitflags! {
+    #[derive(BitFlags, Debug, Clone, Copy, PartialEq)]
+    struct Permissions: u8 {
+        const READ = 1;
+        const WRITE = 2;
+        const EXECUTE = 4;
+    }
+}
+
+// Same wire type as Permissions, but keeps bits it doesn't know about
+femtoflatbuffers::bitflags::bitflags! {
+    #[derive(BitFlags, Debug, Clone, Copy, PartialEq)]
+    #[femto(unknown)]
+    struct OpenPermissions: u8 {
+        const READ = 1;
+        const WRITE = 2;
+    }
+}
+
+#[derive(Table, Debug)]
+struct FlagsTest<'a> {
+    a: Permissions,
+    b: Vector<'a, Permissions>,
+    c: i32
+}
+
+#[derive(Table, Debug)]
+struct OpenFlagsTest<'a> {
+    a: OpenPermissions,
+    b: Vector<'a, OpenPermissions>,
+    c: i32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn build_flags_test(a: test::test::Permissions, b: &[test::test::Permissions]) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let b = builder.create_vector(b);
+    let mut table_builder = test::test::FlagsTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    table_builder.add_b(b);
+    table_builder.add_c(5);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn encode_test() {
+    let encoded_test = build_flags_test(test::test::Permissions::Read, &[test::test::Permissions::Write, test::test::Permissions::all()]);
+    let borrowed_test = FlagsTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    let test = FlagsTest{
+        a: Permissions::READ | Permissions::EXECUTE,
+        b: borrowed_test.b,
+        c: 6
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::FlagsTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), test::test::Permissions::Read | test::test::Permissions::Execute);
+    assert_eq!(decoded_test.b().unwrap().iter().collect::<Vec<_>>(), vec![test::test::Permissions::Write, test::test::Permissions::all()]);
+    assert_eq!(decoded_test.c(), 6);
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_flags_test(test::test::Permissions::Read | test::test::Permissions::Write, &[test::test::Permissions::Execute]);
+    println!("{:x?}", encoded_test);
+    let decoded_test = FlagsTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a, Permissions::READ | Permissions::WRITE);
+    assert_eq!(decoded_test.b.get(0).unwrap(), Permissions::EXECUTE);
+    assert_eq!(decoded_test.c, 5);
+}
+
+#[test]
+fn decode_unknown_test() {
+    let encoded_test = build_flags_test(test::test::Permissions::from_bits_retain(0x81), &[test::test::Permissions::Execute]);
+    let result = FlagsTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(femtoflatbuffers::DecodeError::UnknownFlags)));
+
+    // The decoder can keep them for every flags type
+    let decoded_test = FlagsTest::decode(&Decoder::new(&encoded_test).with_unknown_flags()).unwrap();
+    assert_eq!(decoded_test.a.bits(), 0x81);
+
+    let decoded_test = OpenFlagsTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a, OpenPermissions::READ | OpenPermissions::from_bits_retain(0x80));
+    assert_eq!(decoded_test.b.get(0).unwrap().bits(), 4);
+
+    // Unknown bits are written back unchanged
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::FlagsTest>(encoder.done()).unwrap();
+    assert_eq!(decoded_test.a().bits(), 0x81);
+}
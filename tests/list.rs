@@ -33,7 +33,7 @@ fn encode_test() {
     let encoded = encoder.done();
     println!("{:x?}", encoded);
 
-    let decoded_test = flatbuffers::root::<test::test::ListTest>(&encoded).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::ListTest>(encoded).unwrap();
     println!("{:?}", decoded_test);
 }
 
@@ -65,6 +65,6 @@ fn decode_test() {
         builder.finished_data()
     };
     println!("{:x?}", encoded_test);
-    let decoded_test = ListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    let decoded_test = ListTest::decode(&Decoder::new(encoded_test)).unwrap();
     println!("{:?}", decoded_test);
 }
\ No newline at end of file
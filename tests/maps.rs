@@ -0,0 +1,227 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::BTreeMap;
+
+use femtoflatbuffers::{Decoder, DecodeError, Table};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: u32,
+    b: u32,
+    c: u32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct MapTest {
+    a: BTreeMap<i32, String>,
+    b: BTreeMap<String, Test>,
+    c: i32
+}
+
+#[cfg(feature = "std")]
+#[derive(Table, Debug, PartialEq)]
+struct HashMapTest<'a> {
+    a: std::collections::HashMap<i32, &'a str>,
+    b: std::collections::HashMap<&'a str, Test>,
+    c: i32
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Table, Debug, PartialEq)]
+struct HeaplessMapTest {
+    a: heapless::LinearMap<i32, heapless::String<8>, 4>,
+    b: heapless::index_map::FnvIndexMap<heapless::String<8>, Test, 4>,
+    c: i32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn test_value(x: u32) -> Test {
+    Test{a: x, b: x + 1, c: x + 2}
+}
+
+fn build_map_test(a: &[(i32, &str)], b: &[(&str, u32)]) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let mut a_entries = vec![];
+    for (key, value) in a {
+        let value = builder.create_string(value);
+        a_entries.push(test::test::IntEntry::create(&mut builder, &test::test::IntEntryArgs{key: *key, value: Some(value)}));
+    }
+    let a = builder.create_vector(&a_entries);
+    let mut b_entries = vec![];
+    for (key, value) in b {
+        let key = builder.create_string(key);
+        let value = test::test::Test::create(&mut builder, &test::test::TestArgs{a: *value as i32, b: *value as i32 + 1, c: *value as i32 + 2});
+        b_entries.push(test::test::StringEntry::create(&mut builder, &test::test::StringEntryArgs{key: Some(key), value: Some(value)}));
+    }
+    let b = builder.create_vector(&b_entries);
+    let mut table_builder = test::test::MapTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    table_builder.add_b(b);
+    table_builder.add_c(9);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+// Checks the entries are sorted the way flatc's binary search expects
+fn check_map_test(encoded: &[u8]) {
+    let decoded_test = flatbuffers::root::<test::test::MapTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    let a = decoded_test.a().unwrap();
+    assert_eq!(a.iter().map(|entry| entry.key()).collect::<Vec<_>>(), vec![-300, -2, 0, 7]);
+    for (key, value) in [(-300, "low"), (-2, "minus two"), (0, "zero"), (7, "seven")] {
+        let entry = a.lookup_by_key(key, |entry, key| entry.key_compare_with_value(*key)).unwrap();
+        assert_eq!(entry.value(), Some(value));
+    }
+    assert!(a.lookup_by_key(1, |entry, key| entry.key_compare_with_value(*key)).is_none());
+
+    let b = decoded_test.b().unwrap();
+    assert_eq!(b.iter().map(|entry| entry.key()).collect::<Vec<_>>(), vec!["", "Zebra", "apple", "banana"]);
+    for (key, value) in [("", 1), ("Zebra", 4), ("apple", 7), ("banana", 10)] {
+        let entry = b.lookup_by_key(key, |entry, key| entry.key_compare_with_value(key)).unwrap();
+        assert_eq!(entry.value().unwrap().a(), value);
+    }
+    assert!(b.lookup_by_key("cherry", |entry, key| entry.key_compare_with_value(key)).is_none());
+    assert_eq!(decoded_test.c(), 9);
+}
+
+fn map_test() -> MapTest {
+    MapTest{
+        a: BTreeMap::from([(7, "seven".to_string()), (-2, "minus two".to_string()), (0, "zero".to_string()), (-300, "low".to_string())]),
+        b: BTreeMap::from([
+            ("banana".to_string(), test_value(10)),
+            ("apple".to_string(), test_value(7)),
+            ("Zebra".to_string(), test_value(4)),
+            ("".to_string(), test_value(1))
+        ]),
+        c: 9
+    }
+}
+
+#[test]
+fn encode_test() {
+    let test = map_test();
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    check_map_test(encoded);
+
+    let decoded_test = MapTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_map_test(&[(-3, "a"), (5, "b")], &[("x", 1), ("y", 2)]);
+    let decoded_test = MapTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a, BTreeMap::from([(-3, "a".to_string()), (5, "b".to_string())]));
+    assert_eq!(decoded_test.b, BTreeMap::from([("x".to_string(), test_value(1)), ("y".to_string(), test_value(2))]));
+    assert_eq!(decoded_test.c, 9);
+}
+
+#[test]
+fn duplicate_key_test() {
+    let encoded_test = build_map_test(&[(1, "a"), (1, "b")], &[]);
+    let result = MapTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(DecodeError::DuplicateKey)));
+
+    let encoded_test = build_map_test(&[], &[("x", 1), ("x", 2)]);
+    let result = MapTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(DecodeError::DuplicateKey)));
+}
+
+#[test]
+fn absent_value_test() {
+    // flatc trims the value's entry off the end of the vtable when it isn't set
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let entry = test::test::IntEntry::create(&mut builder, &test::test::IntEntryArgs{key: 4, value: None});
+    let a = builder.create_vector(&[entry]);
+    let table = test::test::MapTest::create(&mut builder, &test::test::MapTestArgs{a: Some(a), b: None, c: 9});
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    let decoded_test = MapTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert_eq!(decoded_test.a, BTreeMap::from([(4, String::new())]));
+}
+
+#[test]
+fn empty_test() {
+    let test = MapTest{a: BTreeMap::new(), b: BTreeMap::new(), c: 9};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    let decoded_test = flatbuffers::root::<test::test::MapTest>(encoded).unwrap();
+    assert!(decoded_test.a().is_none());
+    assert!(decoded_test.b().is_none());
+
+    let decoded_test = MapTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_test() {
+    let test = HashMapTest{
+        a: [(7, "seven"), (-2, "minus two"), (0, "zero"), (-300, "low")].into(),
+        b: [("banana", test_value(10)), ("apple", test_value(7)), ("Zebra", test_value(4)), ("", test_value(1))].into(),
+        c: 9
+    };
+
+    // Encoding is the same whatever order the map iterates in
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    check_map_test(encoded);
+
+    let mut expected_buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut expected_buffer);
+    map_test().encode(&mut encoder).unwrap();
+    assert_eq!(encoded, encoder.done());
+
+    let decoded_test = HashMapTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_test() {
+    let mut a = heapless::LinearMap::new();
+    let mut b = heapless::index_map::FnvIndexMap::new();
+    for (key, value) in [(7, "seven"), (-2, "minus"), (0, "zero"), (-300, "low")] {
+        a.insert(key, value.try_into().unwrap()).unwrap();
+    }
+    for (key, value) in [("banana", 10), ("apple", 7), ("Zebra", 4), ("", 1)] {
+        b.insert(key.try_into().unwrap(), test_value(value)).unwrap();
+    }
+    let test = HeaplessMapTest{a, b, c: 9};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    let decoded_test = flatbuffers::root::<test::test::MapTest>(encoded).unwrap();
+    let a = decoded_test.a().unwrap();
+    assert_eq!(a.iter().map(|entry| entry.key()).collect::<Vec<_>>(), vec![-300, -2, 0, 7]);
+    assert_eq!(a.lookup_by_key(-2, |entry, key| entry.key_compare_with_value(*key)).unwrap().value(), Some("minus"));
+    let b = decoded_test.b().unwrap();
+    assert_eq!(b.iter().map(|entry| entry.key()).collect::<Vec<_>>(), vec!["", "Zebra", "apple", "banana"]);
+
+    let decoded_test = HeaplessMapTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+
+    // Decoding more entries than fit is an error rather than silently dropping some
+    let encoded_test = build_map_test(&[(1, "a"), (2, "b"), (3, "c"), (4, "d"), (5, "e")], &[]);
+    let result = HeaplessMapTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(DecodeError::CollectionOverflow)));
+}
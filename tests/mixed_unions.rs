@@ -0,0 +1,211 @@
+#![cfg(feature = "alloc")]
+
+use femtoflatbuffers::{Decoder, EncodeError, RawTable, Struct, Table, Union};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: u32,
+    b: u32,
+    c: u32
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum MixedUnion<'a> {
+    NONE,
+    Test(Test),
+    Vec3(Vec3),
+    Name(&'a str)
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct MixedUnionTest<'a> {
+    a: MixedUnion<'a>,
+    b: Vec<MixedUnion<'a>>,
+    c: u32
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum OwnedMixedUnion {
+    NONE,
+    Test(Test),
+    Vec3(Box<Vec3>),
+    Name(String)
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct OwnedMixedUnionTest {
+    a: OwnedMixedUnion,
+    b: Vec<OwnedMixedUnion>,
+    c: u32
+}
+
+// Only knows about tables, so the struct and string members are unknown
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug)]
+enum OpenMixedUnion<'a> {
+    NONE,
+    Test(Test),
+    #[femto(unknown)]
+    Unknown(u8, RawTable<'a>)
+}
+
+#[derive(Table, Debug)]
+struct OpenMixedUnionTest<'a> {
+    a: OpenMixedUnion<'a>,
+    b: Vec<OpenMixedUnion<'a>>,
+    c: u32
+}
+
+mod common;
+use common::{test, encode};
+
+fn build_mixed_union_test<'a>(builder: &'a mut flatbuffers::FlatBufferBuilder, a_type: test::test::MixedUnion, a: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) -> &'a [u8] {
+    let mut table_builder = test::test::TestBuilder::new(builder);
+    table_builder.add_a(1);
+    table_builder.add_b(2);
+    table_builder.add_c(3);
+    let first = flatbuffers::WIPOffset::new(table_builder.finish().value());
+    let second = flatbuffers::WIPOffset::new(builder.push(test::test::Vec3::new(4.0, 5.0, 6.0)).value());
+    let third = flatbuffers::WIPOffset::new(builder.create_string("seven").value());
+    let b_type = builder.create_vector(&[test::test::MixedUnion::Test, test::test::MixedUnion::Vec3, test::test::MixedUnion::Name]);
+    let b = builder.create_vector(&[first, second, third]);
+    let mut table_builder = test::test::MixedUnionTestBuilder::new(builder);
+    table_builder.add_a_type(a_type);
+    table_builder.add_a(a);
+    table_builder.add_b_type(b_type);
+    table_builder.add_b(b);
+    table_builder.add_c(8);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data()
+}
+
+fn check_mixed_union_list(decoded_test: &test::test::MixedUnionTest) {
+    assert_eq!(decoded_test.b_type().unwrap().iter().collect::<Vec<_>>(), vec![test::test::MixedUnion::Test, test::test::MixedUnion::Vec3, test::test::MixedUnion::Name]);
+    let first = unsafe { test::test::Test::init_from_table(decoded_test.b().unwrap().get(0)) };
+    assert_eq!((first.a(), first.b(), first.c()), (1, 2, 3));
+    let structs = unsafe { decoded_test._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<test::test::Vec3>>>>(test::test::MixedUnionTest::VT_B, None) };
+    let second = structs.unwrap().get(1);
+    assert_eq!((second.x(), second.y(), second.z()), (4.0, 5.0, 6.0));
+    let strings = unsafe { decoded_test._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&str>>>>(test::test::MixedUnionTest::VT_B, None) };
+    assert_eq!(strings.unwrap().get(2), "seven");
+    assert_eq!(decoded_test.c(), 8);
+}
+
+fn mixed_union_list<'a>() -> Vec<MixedUnion<'a>> {
+    vec![MixedUnion::Test(Test{a: 1, b: 2, c: 3}), MixedUnion::Vec3(Vec3{x: 4.0, y: 5.0, z: 6.0}), MixedUnion::Name("seven")]
+}
+
+#[test]
+fn table_member_test() {
+    let test = MixedUnionTest{a: MixedUnion::Test(Test{a: 9, b: 10, c: 11}), b: mixed_union_list(), c: 8};
+    let encoded = encode(&test);
+
+    let decoded_test = flatbuffers::root::<test::test::MixedUnionTest>(&encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a_type(), test::test::MixedUnion::Test);
+    let a = unsafe { test::test::Test::init_from_table(decoded_test.a().unwrap()) };
+    assert_eq!((a.a(), a.b(), a.c()), (9, 10, 11));
+    check_mixed_union_list(&decoded_test);
+
+    assert_eq!(MixedUnionTest::decode(&Decoder::new(&encoded)).unwrap(), test);
+}
+
+#[test]
+fn struct_member_test() {
+    let test = MixedUnionTest{a: MixedUnion::Vec3(Vec3{x: 1.5, y: 2.5, z: 3.5}), b: mixed_union_list(), c: 8};
+    let encoded = encode(&test);
+
+    let decoded_test = flatbuffers::root::<test::test::MixedUnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::MixedUnion::Vec3);
+    let a = unsafe { decoded_test._tab.get::<flatbuffers::ForwardsUOffset<test::test::Vec3>>(test::test::MixedUnionTest::VT_A, None) }.unwrap();
+    assert_eq!((a.x(), a.y(), a.z()), (1.5, 2.5, 3.5));
+    check_mixed_union_list(&decoded_test);
+
+    assert_eq!(MixedUnionTest::decode(&Decoder::new(&encoded)).unwrap(), test);
+}
+
+#[test]
+fn string_member_test() {
+    // Empty strings still need an offset when they're a union member
+    for name in ["name", ""] {
+        let test = MixedUnionTest{a: MixedUnion::Name(name), b: mixed_union_list(), c: 8};
+        let encoded = encode(&test);
+
+        let decoded_test = flatbuffers::root::<test::test::MixedUnionTest>(&encoded).unwrap();
+        assert_eq!(decoded_test.a_type(), test::test::MixedUnion::Name);
+        let a = unsafe { decoded_test._tab.get::<flatbuffers::ForwardsUOffset<&str>>(test::test::MixedUnionTest::VT_A, None) };
+        assert_eq!(a, Some(name));
+        check_mixed_union_list(&decoded_test);
+
+        assert_eq!(MixedUnionTest::decode(&Decoder::new(&encoded)).unwrap(), test);
+    }
+}
+
+#[test]
+fn decode_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = builder.push(test::test::Vec3::new(-1.0, -2.0, -3.0)).as_union_value();
+    let encoded_test = build_mixed_union_test(&mut builder, test::test::MixedUnion::Vec3, a);
+    println!("{:x?}", encoded_test);
+    let decoded_test = MixedUnionTest::decode(&Decoder::new(encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, MixedUnionTest{a: MixedUnion::Vec3(Vec3{x: -1.0, y: -2.0, z: -3.0}), b: mixed_union_list(), c: 8});
+
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = builder.create_string("name").as_union_value();
+    let encoded_test = build_mixed_union_test(&mut builder, test::test::MixedUnion::Name, a);
+    let decoded_test = OwnedMixedUnionTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert_eq!(decoded_test, OwnedMixedUnionTest{
+        a: OwnedMixedUnion::Name("name".to_string()),
+        b: vec![
+            OwnedMixedUnion::Test(Test{a: 1, b: 2, c: 3}),
+            OwnedMixedUnion::Vec3(Box::new(Vec3{x: 4.0, y: 5.0, z: 6.0})),
+            OwnedMixedUnion::Name("seven".to_string())
+        ],
+        c: 8
+    });
+
+    // Owned members encode the same way
+    let encoded = encode(&decoded_test);
+    let decoded_test = flatbuffers::root::<test::test::MixedUnionTest>(&encoded).unwrap();
+    check_mixed_union_list(&decoded_test);
+}
+
+#[test]
+fn unknown_member_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = builder.create_string("name").as_union_value();
+    let encoded_test = build_mixed_union_test(&mut builder, test::test::MixedUnion::Name, a);
+    let mut decoded_test = OpenMixedUnionTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert!(matches!(decoded_test.a, OpenMixedUnion::Unknown(3, _)));
+    assert!(matches!(decoded_test.b[..], [OpenMixedUnion::Test(Test{a: 1, b: 2, c: 3}), OpenMixedUnion::Unknown(2, _), OpenMixedUnion::Unknown(3, _)]));
+
+    // Nothing says how big an unknown struct is, so it can't be copied
+    let OpenMixedUnion::Unknown(_, member) = &decoded_test.b[1] else { unreachable!() };
+    assert!(member.bytes().is_none());
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    assert!(matches!(decoded_test.encode(&mut encoder), Err(EncodeError::InvalidStructure)));
+
+    decoded_test.b.remove(1);
+    let encoded = encode(&decoded_test);
+    let decoded_test = flatbuffers::root::<test::test::MixedUnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::MixedUnion::Name);
+    let a = unsafe { decoded_test._tab.get::<flatbuffers::ForwardsUOffset<&str>>(test::test::MixedUnionTest::VT_A, None) };
+    assert_eq!(a, Some("name"));
+    assert_eq!(decoded_test.b_type().unwrap().iter().collect::<Vec<_>>(), vec![test::test::MixedUnion::Test, test::test::MixedUnion::Name]);
+    let strings = unsafe { decoded_test._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<flatbuffers::ForwardsUOffset<&str>>>>(test::test::MixedUnionTest::VT_B, None) };
+    assert_eq!(strings.unwrap().get(1), "seven");
+    assert_eq!(decoded_test.c(), 8);
+}
@@ -36,7 +36,7 @@ fn encode_test() {
     test.encode(&mut encoder).unwrap();
     let encoded = encoder.done();
     println!("{:x?}", encoded);
-    let decoded_test = flatbuffers::root::<test::test::NestingTest>(&encoded).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::NestingTest>(encoded).unwrap();
     println!("{:?}", decoded_test);
 }
 
@@ -58,6 +58,6 @@ fn decode_test() {
         builder.finished_data()
     };
     println!("{:x?}", encoded_test);
-    let decoded_test = NestingTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    let decoded_test = NestingTest::decode(&Decoder::new(encoded_test)).unwrap();
     println!("{:?}", decoded_test);
 }
@@ -0,0 +1,180 @@
+#![cfg(feature = "alloc")]
+
+use femtoflatbuffers::{Decoder, Struct, Table, Union};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: u32,
+    b: u32,
+    c: u32
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Particle {
+    id: u8,
+    position: Vec3,
+    mass: f64,
+    active: bool
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+#[femto(transparent)]
+struct ParticleId(u8);
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+#[femto(transparent)]
+struct Position(Vec3);
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+#[femto(transparent)]
+struct Kilograms {
+    value: f64
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct WrappedParticle {
+    id: ParticleId,
+    position: Position,
+    mass: Kilograms,
+    active: bool
+}
+
+#[derive(Table, Debug, PartialEq)]
+#[femto(transparent)]
+struct DeviceId(i32);
+
+#[derive(Table, Debug, PartialEq)]
+struct StructTest {
+    a: Vec3,
+    b: Particle,
+    c: Vec<Vec3>,
+    d: Vec<Particle>,
+    e: i32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct WrappedStructTest {
+    a: Position,
+    b: WrappedParticle,
+    c: Vec<Position>,
+    d: Vec<WrappedParticle>,
+    e: DeviceId
+}
+
+#[derive(Table, Debug, PartialEq)]
+#[femto(transparent)]
+struct Name<'a>(&'a str);
+
+#[derive(Table, Debug, PartialEq)]
+#[femto(transparent)]
+struct Wrapper<T> {
+    inner: T
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum MixedUnion<'a> {
+    NONE,
+    Test(Test),
+    Vec3(Vec3),
+    Name(&'a str)
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum WrappedMixedUnion<'a> {
+    NONE,
+    Test(Wrapper<Test>),
+    Vec3(Position),
+    Name(Name<'a>)
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct MixedUnionTest<'a> {
+    a: MixedUnion<'a>,
+    b: Vec<MixedUnion<'a>>,
+    c: u32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct WrappedMixedUnionTest<'a> {
+    a: WrappedMixedUnion<'a>,
+    b: Wrapper<Vec<WrappedMixedUnion<'a>>>,
+    c: Wrapper<u32>
+}
+
+mod common;
+use common::{test, encode};
+
+fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
+    Vec3{x, y, z}
+}
+
+#[test]
+fn struct_test() {
+    let particles = [(1, vec3(0.5, 0.25, 0.125), 10.0, true), (2, vec3(-0.5, -0.25, -0.125), 20.0, false)];
+    let test = StructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: Particle{id: 7, position: vec3(-1.0, -2.0, -3.0), mass: 1.0e10, active: true},
+        c: vec![vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)],
+        d: particles.iter().map(|&(id, position, mass, active)| Particle{id, position, mass, active}).collect(),
+        e: 42
+    };
+    let wrapped_test = WrappedStructTest{
+        a: Position(vec3(1.5, 2.5, 3.5)),
+        b: WrappedParticle{id: ParticleId(7), position: Position(vec3(-1.0, -2.0, -3.0)), mass: Kilograms{value: 1.0e10}, active: true},
+        c: vec![Position(vec3(1.0, 2.0, 3.0)), Position(vec3(4.0, 5.0, 6.0))],
+        d: particles.iter().map(|&(id, position, mass, active)| WrappedParticle{
+            id: ParticleId(id),
+            position: Position(position),
+            mass: Kilograms{value: mass},
+            active
+        }).collect(),
+        e: DeviceId(42)
+    };
+
+    // Newtypes leave no trace in the buffer
+    let encoded = encode(&wrapped_test);
+    assert_eq!(encoded, encode(&test));
+    let decoded_test = flatbuffers::root::<test::test::StructTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.b().unwrap().mass(), 1.0e10);
+    assert_eq!(decoded_test.e(), 42);
+
+    let decoded_test = WrappedStructTest::decode(&Decoder::new(&encoded)).unwrap();
+    assert_eq!(decoded_test, wrapped_test);
+}
+
+#[test]
+fn union_test() {
+    let test = MixedUnionTest{
+        a: MixedUnion::Name("first"),
+        b: vec![MixedUnion::Test(Test{a: 1, b: 2, c: 3}), MixedUnion::Vec3(vec3(1.0, 2.0, 3.0)), MixedUnion::Name("last")],
+        c: 9
+    };
+    let wrapped_test = WrappedMixedUnionTest{
+        a: WrappedMixedUnion::Name(Name("first")),
+        b: Wrapper{inner: vec![
+            WrappedMixedUnion::Test(Wrapper{inner: Test{a: 1, b: 2, c: 3}}),
+            WrappedMixedUnion::Vec3(Position(vec3(1.0, 2.0, 3.0))),
+            WrappedMixedUnion::Name(Name("last"))
+        ]},
+        c: Wrapper{inner: 9}
+    };
+
+    let encoded = encode(&wrapped_test);
+    assert_eq!(encoded, encode(&test));
+    let decoded_test = flatbuffers::root::<test::test::MixedUnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::MixedUnion::Name);
+    assert_eq!(decoded_test.c(), 9);
+
+    let decoded_test = WrappedMixedUnionTest::decode(&Decoder::new(&encoded)).unwrap();
+    assert_eq!(decoded_test, wrapped_test);
+}
@@ -0,0 +1,69 @@
+use femtoflatbuffers::{Decoder, Struct, Table, Vector, VectorIter};
+use femtoflatbuffers::table::Table;
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Sample(u16, f32);
+
+#[derive(Table, Debug, PartialEq)]
+struct Ack(u32, Option<u16>);
+
+#[derive(Table, Debug, PartialEq)]
+struct Empty;
+
+// Generic over the vector so it can be written from an iterator, the same table is read back without allocating
+#[derive(Table)]
+#[femto(encode_only)]
+struct PositionalIterTest<D>(Ack, Empty, Sample, D);
+
+#[derive(Table, Debug)]
+struct PositionalTest<'a>(Ack, Empty, Sample, Vector<'a, Sample>);
+
+mod common;
+use common::{test, encode};
+
+const SAMPLES: [Sample; 2] = [Sample(1, 0.5), Sample(2, -0.25)];
+
+#[test]
+fn encode_test() {
+    let test = PositionalIterTest(Ack(7, Some(64)), Empty, Sample(3, 1.5), VectorIter::new(SAMPLES.into_iter()));
+
+    let encoded = encode(&test);
+
+    let decoded_test = flatbuffers::root::<test::test::PositionalTest>(&encoded).unwrap();
+    let ack = decoded_test.a().unwrap();
+    assert_eq!((ack.seq(), ack.window()), (7, 64));
+    assert!(decoded_test.b().is_some());
+    assert_eq!((decoded_test.c().unwrap().id(), decoded_test.c().unwrap().value()), (3, 1.5));
+    let d = decoded_test.d().unwrap();
+    assert_eq!((d.get(1).id(), d.get(1).value()), (2, -0.25));
+
+    let encoded = encode(&Ack(7, None));
+    let decoded_test = flatbuffers::root::<test::test::Ack>(&encoded).unwrap();
+    assert_eq!((decoded_test.seq(), decoded_test.window()), (7, 0));
+
+    flatbuffers::root::<test::test::Empty>(&encode(&Empty)).unwrap();
+}
+
+#[test]
+fn decode_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = test::test::Ack::create(&mut builder, &test::test::AckArgs{seq: 7, window: 64});
+    let b = test::test::Empty::create(&mut builder, &test::test::EmptyArgs{});
+    let d = builder.create_vector(&[test::test::Sample::new(1, 0.5), test::test::Sample::new(2, -0.25)]);
+    let table = test::test::PositionalTest::create(&mut builder, &test::test::PositionalTestArgs{
+        a: Some(a),
+        b: Some(b),
+        c: Some(&test::test::Sample::new(3, 1.5)),
+        d: Some(d)
+    });
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    let decoded_test = PositionalTest::decode(&Decoder::new(encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.0, Ack(7, Some(64)));
+    assert_eq!(decoded_test.1, Empty);
+    assert_eq!(decoded_test.2, Sample(3, 1.5));
+    assert_eq!(decoded_test.3.len(), 2);
+    assert_eq!(decoded_test.3.get(1).unwrap(), SAMPLES[1]);
+}
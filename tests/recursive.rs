@@ -0,0 +1,200 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use femtoflatbuffers::{Decoder, DecodeError, Table, Union};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Node {
+    name: String,
+    children: Vec<Node>,
+    parent: Option<Box<Node>>,
+    value: i32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct SharedStringTest<'a> {
+    a: Cow<'a, str>,
+    b: Arc<i32>
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct SharedNode {
+    name: String,
+    children: Vec<Rc<SharedNode>>,
+    parent: Option<Rc<SharedNode>>,
+    value: Rc<i32>
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum NodeUnion {
+    NONE,
+    Node(Node),
+    Name(String)
+}
+
+// A bit of everything that follows an offset, for corrupting
+#[derive(Table, Debug, PartialEq)]
+struct HostileTest {
+    a: Node,
+    b: NodeUnion,
+    c: Vec<NodeUnion>,
+    d: BTreeMap<String, Node>
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn leaf(name: &str, value: i32) -> Node {
+    Node{name: name.to_string(), children: vec![], parent: None, value}
+}
+
+fn build_chain(len: usize) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let mut parent = None;
+    for idx in 0..len {
+        let name = builder.create_string("link");
+        let mut table_builder = test::test::NodeBuilder::new(&mut builder);
+        table_builder.add_name(name);
+        table_builder.add_value(idx as i32 + 1);
+        if let Some(parent) = parent {
+            table_builder.add_parent(parent);
+        }
+        parent = Some(table_builder.finish());
+    }
+    builder.finish(parent.unwrap(), None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn encode_test() {
+    let test = Node{
+        name: "root".to_string(),
+        children: vec![
+            leaf("a", 2),
+            Node{name: "b".to_string(), children: vec![leaf("c", 4)], parent: None, value: 3}
+        ],
+        parent: Some(Box::new(leaf("up", 5))),
+        value: 1
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::Node>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.name(), Some("root"));
+    let children = decoded_test.children().unwrap();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children.get(0).name(), Some("a"));
+    assert_eq!(children.get(1).children().unwrap().get(0).value(), 4);
+    assert_eq!(decoded_test.parent().unwrap().name(), Some("up"));
+
+    let decoded_test = Node::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_chain(3);
+    let decoded_test = Node::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.value, 3);
+    assert_eq!(decoded_test.parent.as_ref().unwrap().value, 2);
+    assert_eq!(decoded_test.parent.as_ref().unwrap().parent.as_ref().unwrap().value, 1);
+    assert!(decoded_test.parent.as_ref().unwrap().parent.as_ref().unwrap().parent.is_none());
+}
+
+#[test]
+fn depth_limit_test() {
+    let encoded_test = build_chain(100);
+    let result = Node::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(DecodeError::DepthLimitExceeded)));
+
+    let decoded_test = Node::decode(&Decoder::with_max_depth(&encoded_test, 100)).unwrap();
+    assert_eq!(decoded_test.value, 100);
+
+    let result = Node::decode(&Decoder::with_max_depth(&build_chain(3), 2));
+    assert!(matches!(result, Err(DecodeError::DepthLimitExceeded)));
+}
+
+#[test]
+fn shared_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = builder.create_string("shared");
+    let mut table_builder = test::test::StringTestBuilder::new(&mut builder);
+    table_builder.add_a(a);
+    table_builder.add_b(8);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    let decoded_test = SharedStringTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert!(matches!(decoded_test.a, Cow::Borrowed("shared")));
+    assert_eq!(*decoded_test.b, 8);
+
+    let test = SharedStringTest{a: Cow::Owned("owned".to_string()), b: Arc::new(9)};
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::StringTest>(encoder.done()).unwrap();
+    assert_eq!(decoded_test.a(), Some("owned"));
+    assert_eq!(decoded_test.b(), 9);
+}
+
+#[test]
+fn shared_node_test() {
+    // The same child shows up twice, it's written out once per reference
+    let child = Rc::new(SharedNode{name: "child".into(), children: vec![], parent: None, value: Rc::new(2)});
+    let test = SharedNode{name: "root".into(), children: vec![child.clone(), child], parent: None, value: Rc::new(1)};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+
+    let decoded_test = flatbuffers::root::<test::test::Node>(encoded).unwrap();
+    assert_eq!(decoded_test.children().unwrap().get(1).name(), Some("child"));
+
+    let decoded_test = SharedNode::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[test]
+fn corrupted_buffer_test() {
+    let test = HostileTest{
+        a: Node{name: "root".to_string(), children: vec![leaf("a", 2)], parent: Some(Box::new(leaf("up", 3))), value: 1},
+        b: NodeUnion::Node(leaf("b", 4)),
+        c: vec![NodeUnion::Name("c".to_string()), NodeUnion::Node(leaf("d", 5))],
+        d: BTreeMap::from([("e".to_string(), leaf("f", 6))])
+    };
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done().to_vec();
+    assert_eq!(HostileTest::decode(&Decoder::new(&encoded)).unwrap(), test);
+
+    // Any of these may fail to decode, none of them may panic
+    for idx in 0..encoded.len() {
+        for value in [0x00, 0x01, 0x7f, 0x80, 0xfc, 0xff] {
+            let mut corrupted = encoded.clone();
+            corrupted[idx] = value;
+            let _ = HostileTest::decode(&Decoder::new(&corrupted));
+        }
+        for value in [i32::MIN, i32::MAX, -4, -(idx as i32), 0x7fff_fffc] {
+            let mut corrupted = encoded.clone();
+            let end = (idx + 4).min(corrupted.len());
+            corrupted[idx..end].copy_from_slice(&value.to_le_bytes()[..end - idx]);
+            let _ = HostileTest::decode(&Decoder::new(&corrupted));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use femtoflatbuffers::{Decoder, Table};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct ScalarTest {
+    a: bool,
+    b: i8,
+    c: f32,
+    d: f64
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Table, Debug, PartialEq)]
+struct ScalarListTest {
+    a: Vec<bool>,
+    b: Vec<i8>,
+    c: Vec<f32>,
+    d: Vec<f64>
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+#[test]
+fn encode_test() {
+    let test = ScalarTest{
+        a: true,
+        b: -5,
+        c: 1.5,
+        d: -2.25
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::ScalarTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert!(decoded_test.a());
+    assert_eq!(decoded_test.b(), -5);
+    assert_eq!(decoded_test.c(), 1.5);
+    assert_eq!(decoded_test.d(), -2.25);
+}
+
+#[test]
+fn decode_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let encoded_test = {
+        let mut table_builder = test::test::ScalarTestBuilder::new(&mut builder);
+        table_builder.add_a(true);
+        table_builder.add_b(-5);
+        table_builder.add_c(1.5);
+        table_builder.add_d(-2.25);
+        let table = table_builder.finish();
+        builder.finish(table, None);
+        builder.finished_data()
+    };
+    println!("{:x?}", encoded_test);
+    let decoded_test = ScalarTest::decode(&Decoder::new(encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, ScalarTest{a: true, b: -5, c: 1.5, d: -2.25});
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_list_test() {
+    let test = ScalarListTest{
+        a: vec![true, false, true],
+        b: vec![-1, 2, -3],
+        c: vec![0.5, -0.25],
+        d: vec![1.0e100, -3.5, 7.0]
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::ScalarListTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a().unwrap().iter().collect::<Vec<_>>(), test.a);
+    assert_eq!(decoded_test.b().unwrap().iter().collect::<Vec<_>>(), test.b);
+    assert_eq!(decoded_test.c().unwrap().iter().collect::<Vec<_>>(), test.c);
+    assert_eq!(decoded_test.d().unwrap().iter().collect::<Vec<_>>(), test.d);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_list_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let encoded_test = {
+        let a = builder.create_vector(&[true, false, true]);
+        let b = builder.create_vector(&[-1i8, 2, -3]);
+        let c = builder.create_vector(&[0.5f32, -0.25]);
+        let d = builder.create_vector(&[1.0e100f64, -3.5, 7.0]);
+        let mut table_builder = test::test::ScalarListTestBuilder::new(&mut builder);
+        table_builder.add_a(a);
+        table_builder.add_b(b);
+        table_builder.add_c(c);
+        table_builder.add_d(d);
+        let table = table_builder.finish();
+        builder.finish(table, None);
+        builder.finished_data()
+    };
+    println!("{:x?}", encoded_test);
+    let decoded_test = ScalarListTest::decode(&Decoder::new(encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, ScalarListTest{
+        a: vec![true, false, true],
+        b: vec![-1, 2, -3],
+        c: vec![0.5, -0.25],
+        d: vec![1.0e100, -3.5, 7.0]
+    });
+}
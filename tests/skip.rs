@@ -0,0 +1,69 @@
+use core::cell::Cell;
+
+use femtoflatbuffers::{Decoder, Table};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: i32,
+    b: i32,
+    c: i32
+}
+
+// Runtime state, none of which implements the component traits
+#[derive(Debug, PartialEq, Default)]
+struct Handle {
+    open: bool
+}
+
+fn unindexed() -> usize {
+    usize::MAX
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct SkipTest {
+    #[femto(skip)]
+    handle: Handle,
+    a: i32,
+    #[femto(skip, default = "unindexed")]
+    index: usize,
+    b: i32,
+    #[femto(skip)]
+    hits: Cell<u32>,
+    c: i32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+#[test]
+fn encode_test() {
+    let test = SkipTest{handle: Handle{open: true}, a: 1, index: 4, b: 2, hits: Cell::new(9), c: 3};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::Test>(encoded).unwrap();
+    assert_eq!((decoded_test.a(), decoded_test.b(), decoded_test.c()), (1, 2, 3));
+
+    // No vtable slots for the skipped fields
+    let mut expected_buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut expected_buffer);
+    Test{a: 1, b: 2, c: 3}.encode(&mut encoder).unwrap();
+    assert_eq!(encoded, encoder.done());
+}
+
+#[test]
+fn decode_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let table = test::test::Test::create(&mut builder, &test::test::TestArgs{a: 1, b: 2, c: 3});
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    let decoded_test = SkipTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert_eq!(decoded_test, SkipTest{handle: Handle{open: false}, a: 1, index: usize::MAX, b: 2, hits: Cell::new(0), c: 3});
+}
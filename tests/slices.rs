@@ -0,0 +1,257 @@
+use femtoflatbuffers::{Decoder, DecodeError, Struct, Table, Union, VectorIter};
+use femtoflatbuffers::table::{Table, TableEncode};
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Particle {
+    id: u8,
+    position: Vec3,
+    mass: f64,
+    active: bool
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: u32,
+    b: u32,
+    c: u32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct Test2 {
+    d: u32,
+    e: u32,
+    f: u32
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum TestUnion {
+    NONE,
+    A(Test),
+    B(Test2)
+}
+
+#[derive(Table, Debug)]
+#[femto(encode_only)]
+struct SliceStructTest<'a> {
+    a: Vec3,
+    b: Particle,
+    c: &'a [Vec3],
+    d: &'a [Particle],
+    e: i32
+}
+
+#[derive(Table)]
+#[femto(encode_only)]
+struct IterStructTest<C, D> {
+    a: Vec3,
+    b: Particle,
+    c: C,
+    d: D,
+    e: i32
+}
+
+#[derive(Table, Debug)]
+#[femto(encode_only)]
+struct SliceListTest<'a> {
+    a: u32,
+    b: &'a [Test]
+}
+
+#[derive(Table, Debug)]
+#[femto(encode_only)]
+struct SliceStringListTest<'a> {
+    a: &'a [&'a str],
+    b: i32
+}
+
+#[derive(Table, Debug)]
+#[femto(encode_only)]
+struct SliceUnionListTest<'a> {
+    a: &'a [TestUnion],
+    b: u32
+}
+
+// Arrays as vectors rather than inline, so this can decode as well
+#[derive(Table, Debug, PartialEq)]
+struct ArrayStructTest {
+    a: Vec3,
+    b: Particle,
+    #[femto(vector)]
+    c: [Vec3; 2],
+    #[femto(vector)]
+    d: [Particle; 2],
+    e: i32
+}
+
+#[derive(Table, Debug)]
+#[femto(encode_only)]
+struct SliceOptionTest<'a> {
+    a: &'a [Option<u32>]
+}
+
+mod common;
+use common::{test, check_struct_test};
+
+fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
+    Vec3{x, y, z}
+}
+
+fn particle(id: u8, position: Vec3, mass: f64, active: bool) -> Particle {
+    Particle{id, position, mass, active}
+}
+
+#[test]
+fn slice_test() {
+    let c = [vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)];
+    let d = [
+        particle(1, vec3(0.5, 0.25, 0.125), 10.0, true),
+        particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false)
+    ];
+    let test = SliceStructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true),
+        c: &c,
+        d: &d,
+        e: 42
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    check_struct_test(encoded);
+}
+
+#[test]
+fn iter_test() {
+    // Computed on the fly, and from an array
+    let test = IterStructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true),
+        c: VectorIter::new((0..2).map(|idx| vec3(1.0 + 3.0*idx as f32, 2.0 + 3.0*idx as f32, 3.0 + 3.0*idx as f32))),
+        d: VectorIter::new([
+            particle(1, vec3(0.5, 0.25, 0.125), 10.0, true),
+            particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false)
+        ].into_iter()),
+        e: 42
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    check_struct_test(encoder.done());
+}
+
+#[test]
+fn array_test() {
+    let test = ArrayStructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true),
+        c: [vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)],
+        d: [
+            particle(1, vec3(0.5, 0.25, 0.125), 10.0, true),
+            particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false)
+        ],
+        e: 42
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    Table::encode(&test, &mut encoder).unwrap();
+    let encoded = encoder.done();
+    check_struct_test(encoded);
+    assert_eq!(ArrayStructTest::decode(&Decoder::new(encoded)).unwrap(), test);
+
+    // Anything but exactly as many elements as the array holds is an error
+    let c = [vec3(1.0, 2.0, 3.0)];
+    let test = SliceStructTest{a: test.a, b: test.b, c: &c, d: &test.d, e: 42};
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    TableEncode::encode(&test, &mut encoder).unwrap();
+    assert!(matches!(ArrayStructTest::decode(&Decoder::new(encoder.done())), Err(DecodeError::InvalidData)));
+}
+
+#[test]
+fn table_slice_test() {
+    let b = [Test{a: 1, b: 2, c: 3}, Test{a: 4, b: 5, c: 6}];
+    let test = SliceListTest{a: 7, b: &b};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::ListTest>(encoder.done()).unwrap();
+    assert_eq!(decoded_test.a(), 7);
+    let b = decoded_test.b().unwrap();
+    assert_eq!(b.len(), 2);
+    assert_eq!(b.get(0).a(), 1);
+    assert_eq!(b.get(1).c(), 6);
+}
+
+#[test]
+fn string_slice_test() {
+    let test = SliceStringListTest{a: &["first", "", "third"], b: 3};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::StringListTest>(encoder.done()).unwrap();
+    assert_eq!(decoded_test.a().unwrap().iter().collect::<Vec<_>>(), vec!["first", "", "third"]);
+    assert_eq!(decoded_test.b(), 3);
+}
+
+#[test]
+fn union_slice_test() {
+    let a = [TestUnion::B(Test2{d: 1, e: 2, f: 3}), TestUnion::A(Test{a: 4, b: 5, c: 6})];
+    let test = SliceUnionListTest{a: &a, b: 8};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::UnionListTest>(encoder.done()).unwrap();
+    let a_type = decoded_test.a_type().unwrap();
+    assert_eq!(a_type.iter().collect::<Vec<_>>(), vec![test::test::TestUnion::B, test::test::TestUnion::A]);
+    let a = decoded_test.a().unwrap();
+    let first = unsafe { test::test::Test2::init_from_table(a.get(0)) };
+    assert_eq!((first.d(), first.e(), first.f()), (1, 2, 3));
+    let second = unsafe { test::test::Test::init_from_table(a.get(1)) };
+    assert_eq!((second.a(), second.b(), second.c()), (4, 5, 6));
+    assert_eq!(decoded_test.b(), 8);
+}
+
+#[test]
+fn empty_test() {
+    let test = SliceStringListTest{a: &[], b: 3};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    let decoded_test = flatbuffers::root::<test::test::StringListTest>(encoded).unwrap();
+    assert!(decoded_test.a().is_none());
+}
+
+#[test]
+fn missing_element_test() {
+    // Vector elements are always there, a None would leave a gap that shifts everything after it
+    let test = SliceOptionTest{a: &[Some(1), None, Some(3)]};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    assert!(matches!(test.encode(&mut encoder), Err(femtoflatbuffers::EncodeError::InvalidStructure)));
+
+    let test = SliceOptionTest{a: &[Some(1), Some(2), Some(3)]};
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    assert_eq!(&encoded[encoded.len() - 16..], &[3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+}
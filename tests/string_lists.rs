@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use femtoflatbuffers::{Decoder, Table};
+use femtoflatbuffers::table::Table;
+
+#[cfg(feature = "alloc")]
+#[derive(Table, Debug, PartialEq)]
+struct StringListTest {
+    a: Vec<String>,
+    b: i32
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Table, Debug, PartialEq)]
+struct BorrowedStringListTest<'a> {
+    a: Vec<&'a str>,
+    b: i32
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Table, Debug, PartialEq)]
+struct HeaplessStringListTest {
+    a: heapless::vec::Vec<heapless::string::String<8>, 4>,
+    b: i32
+}
+
+mod common;
+use common::{build_string_list_test, check_string_list_test};
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_test() {
+    let test = StringListTest{
+        a: vec!["alpha".to_string(), "".to_string(), "gamma δ".to_string()],
+        b: 7
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    check_string_list_test(encoded, &["alpha", "", "gamma δ"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_test() {
+    let encoded_test = build_string_list_test(&["alpha", "", "gamma δ"]);
+    println!("{:x?}", encoded_test);
+    let decoded_test = StringListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, StringListTest{
+        a: vec!["alpha".to_string(), "".to_string(), "gamma δ".to_string()],
+        b: 7
+    });
+
+    let decoded_test = StringListTest::decode(&Decoder::new(&build_string_list_test(&[]))).unwrap();
+    assert!(decoded_test.a.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn borrowed_test() {
+    let encoded_test = build_string_list_test(&["one", "two"]);
+    let decoded_test = BorrowedStringListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a, vec!["one", "two"]);
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    check_string_list_test(encoder.done(), &["one", "two"]);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_encode_test() {
+    let mut a = heapless::vec::Vec::new();
+    a.push("alpha".try_into().unwrap()).unwrap();
+    a.push("".try_into().unwrap()).unwrap();
+    let test = HeaplessStringListTest{a, b: 7};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    check_string_list_test(encoder.done(), &["alpha", ""]);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_decode_test() {
+    let encoded_test = build_string_list_test(&["alpha", "a rather long string"]);
+    let decoded_test = HeaplessStringListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a[0].as_str(), "alpha");
+    assert_eq!(decoded_test.a[1].as_str(), "a rather");
+
+    let encoded_test = build_string_list_test(&["a", "b", "c", "d", "e"]);
+    let decoded_test = HeaplessStringListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a.iter().map(|x| x.as_str()).collect::<Vec<_>>(), vec!["a", "b", "c", "d"]);
+}
@@ -0,0 +1,111 @@
+#![cfg(any(feature = "alloc", feature = "heapless"))]
+
+use femtoflatbuffers::{Decoder, Table};
+use femtoflatbuffers::table::Table;
+
+#[cfg(feature = "alloc")]
+#[derive(Table, Debug, PartialEq)]
+struct StringTest {
+    a: String,
+    b: u32
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Table, Debug, PartialEq)]
+struct HeaplessStringTest {
+    a: heapless::string::String<8>,
+    b: u32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+fn build_string_test(a: Option<&str>, b: i32) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let a = a.map(|a| builder.create_string(a));
+    let mut table_builder = test::test::StringTestBuilder::new(&mut builder);
+    if let Some(a) = a {
+        table_builder.add_a(a);
+    }
+    table_builder.add_b(b);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_test() {
+    let test = StringTest{
+        a: "héllo wörld".to_string(),
+        b: 2
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::StringTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), Some("héllo wörld"));
+    assert_eq!(decoded_test.b(), 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_test() {
+    let encoded_test = build_string_test(Some("héllo wörld"), 2);
+    println!("{:x?}", encoded_test);
+    let decoded_test = StringTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, StringTest{a: "héllo wörld".to_string(), b: 2});
+
+    let encoded_test = build_string_test(None, 3);
+    let decoded_test = StringTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test, StringTest{a: String::new(), b: 3});
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_invalid_utf8_test() {
+    let mut encoded_test = build_string_test(Some("abc"), 2);
+    let position = encoded_test.windows(3).position(|x| x == b"abc").unwrap();
+    encoded_test[position + 1] = 0xff;
+    let result = StringTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result, Err(femtoflatbuffers::DecodeError::InvalidUtf8)));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_encode_test() {
+    let test = HeaplessStringTest{
+        a: heapless::string::String::try_from("höla").unwrap(),
+        b: 2
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+
+    let decoded_test = flatbuffers::root::<test::test::StringTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), Some("höla"));
+    assert_eq!(decoded_test.b(), 2);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_decode_test() {
+    let encoded_test = build_string_test(Some("höla"), 2);
+    let decoded_test = HeaplessStringTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a.as_str(), "höla");
+
+    // Longer strings are truncated to capacity on a character boundary
+    let encoded_test = build_string_test(Some("abcdefgé"), 2);
+    let decoded_test = HeaplessStringTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a.as_str(), "abcdefg");
+}
@@ -0,0 +1,179 @@
+use femtoflatbuffers::{Decoder, Struct, Table, Vector};
+use femtoflatbuffers::components::PrimitiveComponent;
+use femtoflatbuffers::table::Table;
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Vec3 {
+    x: f32,
+    y: f32,
+    z: f32
+}
+
+#[derive(Struct, Debug, PartialEq, Clone, Copy)]
+struct Particle {
+    id: u8,
+    position: Vec3,
+    mass: f64,
+    active: bool
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Table, Debug, PartialEq)]
+struct StructTest {
+    a: Vec3,
+    b: Particle,
+    c: Vec<Vec3>,
+    d: Vec<Particle>,
+    e: i32
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Table, Debug, PartialEq)]
+struct HeaplessStructTest {
+    a: Vec3,
+    b: Particle,
+    c: heapless::vec::Vec<Vec3, 4>,
+    d: heapless::vec::Vec<Particle, 4>,
+    e: i32
+}
+
+#[derive(Table, Debug)]
+struct BorrowedStructTest<'a> {
+    a: Vec3,
+    b: Particle,
+    c: Vector<'a, Vec3>,
+    d: Vector<'a, Particle>,
+    e: i32
+}
+
+mod common;
+use common::{test, check_struct_test};
+
+fn vec3(x: f32, y: f32, z: f32) -> Vec3 {
+    Vec3{x, y, z}
+}
+
+fn particle(id: u8, position: Vec3, mass: f64, active: bool) -> Particle {
+    Particle{id, position, mass, active}
+}
+
+fn build_struct_test() -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let c = builder.create_vector(&[test::test::Vec3::new(1.0, 2.0, 3.0), test::test::Vec3::new(4.0, 5.0, 6.0)]);
+    let d = builder.create_vector(&[
+        test::test::Particle::new(1, &test::test::Vec3::new(0.5, 0.25, 0.125), 10.0, true),
+        test::test::Particle::new(2, &test::test::Vec3::new(-0.5, -0.25, -0.125), 20.0, false)
+    ]);
+    let mut table_builder = test::test::StructTestBuilder::new(&mut builder);
+    table_builder.add_a(&test::test::Vec3::new(1.5, 2.5, 3.5));
+    table_builder.add_b(&test::test::Particle::new(7, &test::test::Vec3::new(-1.0, -2.0, -3.0), 1.0e10, true));
+    table_builder.add_c(c);
+    table_builder.add_d(d);
+    table_builder.add_e(42);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn layout_test() {
+    // Matches the flatc generated structs
+    assert_eq!(Vec3::alignment(), 4);
+    assert_eq!(Vec3::size(), size_of::<test::test::Vec3>());
+    assert_eq!(Particle::alignment(), 8);
+    assert_eq!(Particle::size(), size_of::<test::test::Particle>());
+
+    let mut buffer = [0u8; 64];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true).do_encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    assert_eq!(encoded, test::test::Particle::new(7, &test::test::Vec3::new(-1.0, -2.0, -3.0), 1.0e10, true).0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn encode_test() {
+    let test = StructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true),
+        c: vec![vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)],
+        d: vec![
+            particle(1, vec3(0.5, 0.25, 0.125), 10.0, true),
+            particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false)
+        ],
+        e: 42
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    check_struct_test(encoded);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn decode_test() {
+    let encoded_test = build_struct_test();
+    println!("{:x?}", encoded_test);
+    let decoded_test = StructTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, StructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true),
+        c: vec![vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)],
+        d: vec![
+            particle(1, vec3(0.5, 0.25, 0.125), 10.0, true),
+            particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false)
+        ],
+        e: 42
+    });
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_encode_test() {
+    let test = HeaplessStructTest{
+        a: vec3(1.5, 2.5, 3.5),
+        b: particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true),
+        c: heapless::vec::Vec::from_slice(&[vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)]).unwrap(),
+        d: heapless::vec::Vec::from_slice(&[
+            particle(1, vec3(0.5, 0.25, 0.125), 10.0, true),
+            particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false)
+        ]).unwrap(),
+        e: 42
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    check_struct_test(encoded);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_decode_test() {
+    let encoded_test = build_struct_test();
+    let decoded_test = HeaplessStructTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.c.as_slice(), &[vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0)]);
+    assert_eq!(decoded_test.d[1], particle(2, vec3(-0.5, -0.25, -0.125), 20.0, false));
+}
+
+#[test]
+fn borrowed_test() {
+    let encoded_test = build_struct_test();
+    let decoded_test = BorrowedStructTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a, vec3(1.5, 2.5, 3.5));
+    assert_eq!(decoded_test.b, particle(7, vec3(-1.0, -2.0, -3.0), 1.0e10, true));
+    assert_eq!(decoded_test.c.get(1).unwrap(), vec3(4.0, 5.0, 6.0));
+    assert_eq!(decoded_test.d.get(0).unwrap(), particle(1, vec3(0.5, 0.25, 0.125), 10.0, true));
+    assert_eq!(decoded_test.e, 42);
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    check_struct_test(encoder.done());
+}
@@ -1,3 +1,4 @@
+#![allow(clippy::all, dead_code, unused_imports, mismatched_lifetime_syntaxes)]
 // automatically generated by the FlatBuffers compiler, do not modify
 
 
@@ -801,6 +802,4455 @@ impl core::fmt::Debug for UnionTest<'_> {
       ds.finish()
   }
 }
+pub enum ScalarTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScalarTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScalarTest<'a> {
+  type Inner = ScalarTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ScalarTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScalarTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScalarTestArgs
+  ) -> flatbuffers::WIPOffset<ScalarTest<'bldr>> {
+    let mut builder = ScalarTestBuilder::new(_fbb);
+    builder.add_d(args.d);
+    builder.add_c(args.c);
+    builder.add_b(args.b);
+    builder.add_a(args.a);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> bool {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ScalarTest::VT_A, Some(false)).unwrap()}
+  }
+  #[inline]
+  pub fn b(&self) -> i8 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i8>(ScalarTest::VT_B, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn c(&self) -> f32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f32>(ScalarTest::VT_C, Some(0.0)).unwrap()}
+  }
+  #[inline]
+  pub fn d(&self) -> f64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<f64>(ScalarTest::VT_D, Some(0.0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ScalarTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<bool>("a", Self::VT_A, false)?
+     .visit_field::<i8>("b", Self::VT_B, false)?
+     .visit_field::<f32>("c", Self::VT_C, false)?
+     .visit_field::<f64>("d", Self::VT_D, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScalarTestArgs {
+    pub a: bool,
+    pub b: i8,
+    pub c: f32,
+    pub d: f64,
+}
+impl<'a> Default for ScalarTestArgs {
+  #[inline]
+  fn default() -> Self {
+    ScalarTestArgs {
+      a: false,
+      b: 0,
+      c: 0.0,
+      d: 0.0,
+    }
+  }
+}
+
+pub struct ScalarTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScalarTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: bool) {
+    self.fbb_.push_slot::<bool>(ScalarTest::VT_A, a, false);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: i8) {
+    self.fbb_.push_slot::<i8>(ScalarTest::VT_B, b, 0);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: f32) {
+    self.fbb_.push_slot::<f32>(ScalarTest::VT_C, c, 0.0);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: f64) {
+    self.fbb_.push_slot::<f64>(ScalarTest::VT_D, d, 0.0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScalarTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScalarTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScalarTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScalarTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScalarTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.finish()
+  }
+}
+pub enum ScalarListTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ScalarListTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ScalarListTest<'a> {
+  type Inner = ScalarListTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ScalarListTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ScalarListTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ScalarListTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ScalarListTest<'bldr>> {
+    let mut builder = ScalarListTestBuilder::new(_fbb);
+    if let Some(x) = args.d { builder.add_d(x); }
+    if let Some(x) = args.c { builder.add_c(x); }
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<flatbuffers::Vector<'a, bool>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, bool>>>(ScalarListTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<flatbuffers::Vector<'a, i8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i8>>>(ScalarListTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> Option<flatbuffers::Vector<'a, f32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f32>>>(ScalarListTest::VT_C, None)}
+  }
+  #[inline]
+  pub fn d(&self) -> Option<flatbuffers::Vector<'a, f64>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, f64>>>(ScalarListTest::VT_D, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ScalarListTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, bool>>>("a", Self::VT_A, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i8>>>("b", Self::VT_B, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f32>>>("c", Self::VT_C, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, f64>>>("d", Self::VT_D, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ScalarListTestArgs<'a> {
+    pub a: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, bool>>>,
+    pub b: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i8>>>,
+    pub c: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f32>>>,
+    pub d: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, f64>>>,
+}
+impl<'a> Default for ScalarListTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ScalarListTestArgs {
+      a: None,
+      b: None,
+      c: None,
+      d: None,
+    }
+  }
+}
+
+pub struct ScalarListTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ScalarListTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<flatbuffers::Vector<'b , bool>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScalarListTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<flatbuffers::Vector<'b , i8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScalarListTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScalarListTest::VT_C, c);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: flatbuffers::WIPOffset<flatbuffers::Vector<'b , f64>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ScalarListTest::VT_D, d);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ScalarListTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ScalarListTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ScalarListTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ScalarListTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ScalarListTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.finish()
+  }
+}
+
+pub enum StringTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StringTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StringTest<'a> {
+  type Inner = StringTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> StringTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StringTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StringTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<StringTest<'bldr>> {
+    let mut builder = StringTestBuilder::new(_fbb);
+    builder.add_b(args.b);
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StringTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(StringTest::VT_B, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for StringTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("a", Self::VT_A, false)?
+     .visit_field::<i32>("b", Self::VT_B, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StringTestArgs<'a> {
+    pub a: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub b: i32,
+}
+impl<'a> Default for StringTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StringTestArgs {
+      a: None,
+      b: 0,
+    }
+  }
+}
+
+pub struct StringTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StringTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StringTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: i32) {
+    self.fbb_.push_slot::<i32>(StringTest::VT_B, b, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StringTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StringTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StringTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StringTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StringTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.finish()
+  }
+}
+
+pub enum BorrowedTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct BorrowedTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for BorrowedTest<'a> {
+  type Inner = BorrowedTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> BorrowedTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    BorrowedTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args BorrowedTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<BorrowedTest<'bldr>> {
+    let mut builder = BorrowedTestBuilder::new(_fbb);
+    builder.add_d(args.d);
+    if let Some(x) = args.c { builder.add_c(x); }
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(BorrowedTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(BorrowedTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> Option<flatbuffers::Vector<'a, u16>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u16>>>(BorrowedTest::VT_C, None)}
+  }
+  #[inline]
+  pub fn d(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(BorrowedTest::VT_D, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for BorrowedTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("a", Self::VT_A, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("b", Self::VT_B, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u16>>>("c", Self::VT_C, false)?
+     .visit_field::<i32>("d", Self::VT_D, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct BorrowedTestArgs<'a> {
+    pub a: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub b: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub c: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u16>>>,
+    pub d: i32,
+}
+impl<'a> Default for BorrowedTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    BorrowedTestArgs {
+      a: None,
+      b: None,
+      c: None,
+      d: 0,
+    }
+  }
+}
+
+pub struct BorrowedTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> BorrowedTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BorrowedTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BorrowedTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u16>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(BorrowedTest::VT_C, c);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: i32) {
+    self.fbb_.push_slot::<i32>(BorrowedTest::VT_D, d, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> BorrowedTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    BorrowedTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<BorrowedTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for BorrowedTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("BorrowedTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.finish()
+  }
+}
+
+// struct Vec3, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Vec3(pub [u8; 12]);
+impl Default for Vec3 { 
+  fn default() -> Self { 
+    Self([0; 12])
+  }
+}
+impl core::fmt::Debug for Vec3 {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Vec3")
+      .field("x", &self.x())
+      .field("y", &self.y())
+      .field("z", &self.z())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Vec3 {}
+impl<'a> flatbuffers::Follow<'a> for Vec3 {
+  type Inner = &'a Vec3;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Vec3>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Vec3 {
+  type Inner = &'a Vec3;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Vec3>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Vec3 {
+    type Output = Vec3;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Vec3 as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Vec3 {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Vec3 {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    x: f32,
+    y: f32,
+    z: f32,
+  ) -> Self {
+    let mut s = Self([0; 12]);
+    s.set_x(x);
+    s.set_y(y);
+    s.set_z(z);
+    s
+  }
+
+  pub fn x(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn y(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_y(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn z(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_z(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct Particle, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Particle(pub [u8; 32]);
+impl Default for Particle { 
+  fn default() -> Self { 
+    Self([0; 32])
+  }
+}
+impl core::fmt::Debug for Particle {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Particle")
+      .field("id", &self.id())
+      .field("position", &self.position())
+      .field("mass", &self.mass())
+      .field("active", &self.active())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Particle {}
+impl<'a> flatbuffers::Follow<'a> for Particle {
+  type Inner = &'a Particle;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Particle>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Particle {
+  type Inner = &'a Particle;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Particle>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Particle {
+    type Output = Particle;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Particle as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Particle {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Particle {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: u8,
+    position: &Vec3,
+    mass: f64,
+    active: bool,
+  ) -> Self {
+    let mut s = Self([0; 32]);
+    s.set_id(id);
+    s.set_position(position);
+    s.set_mass(mass);
+    s.set_active(active);
+    s
+  }
+
+  pub fn id(&self) -> u8 {
+    let mut mem = core::mem::MaybeUninit::<<u8 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_id(&mut self, x: u8) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u8 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn position(&self) -> &Vec3 {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid struct in this slot
+    unsafe { &*(self.0[4..].as_ptr() as *const Vec3) }
+  }
+
+  #[allow(clippy::identity_op)]
+  pub fn set_position(&mut self, x: &Vec3) {
+    self.0[4..4 + 12].copy_from_slice(&x.0)
+  }
+
+  pub fn mass(&self) -> f64 {
+    let mut mem = core::mem::MaybeUninit::<<f64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[16..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_mass(&mut self, x: f64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[16..].as_mut_ptr(),
+        core::mem::size_of::<<f64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn active(&self) -> bool {
+    let mut mem = core::mem::MaybeUninit::<<bool as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[24..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_active(&mut self, x: bool) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[24..].as_mut_ptr(),
+        core::mem::size_of::<<bool as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum StructTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StructTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StructTest<'a> {
+  type Inner = StructTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> StructTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+  pub const VT_E: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StructTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StructTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<StructTest<'bldr>> {
+    let mut builder = StructTestBuilder::new(_fbb);
+    builder.add_e(args.e);
+    if let Some(x) = args.d { builder.add_d(x); }
+    if let Some(x) = args.c { builder.add_c(x); }
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<&'a Vec3> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Vec3>(StructTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<&'a Particle> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Particle>(StructTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> Option<flatbuffers::Vector<'a, Vec3>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Vec3>>>(StructTest::VT_C, None)}
+  }
+  #[inline]
+  pub fn d(&self) -> Option<flatbuffers::Vector<'a, Particle>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Particle>>>(StructTest::VT_D, None)}
+  }
+  #[inline]
+  pub fn e(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(StructTest::VT_E, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for StructTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Vec3>("a", Self::VT_A, false)?
+     .visit_field::<Particle>("b", Self::VT_B, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Vec3>>>("c", Self::VT_C, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Particle>>>("d", Self::VT_D, false)?
+     .visit_field::<i32>("e", Self::VT_E, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StructTestArgs<'a> {
+    pub a: Option<&'a Vec3>,
+    pub b: Option<&'a Particle>,
+    pub c: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Vec3>>>,
+    pub d: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Particle>>>,
+    pub e: i32,
+}
+impl<'a> Default for StructTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StructTestArgs {
+      a: None,
+      b: None,
+      c: None,
+      d: None,
+      e: 0,
+    }
+  }
+}
+
+pub struct StructTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StructTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: &Vec3) {
+    self.fbb_.push_slot_always::<&Vec3>(StructTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: &Particle) {
+    self.fbb_.push_slot_always::<&Particle>(StructTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Vec3>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StructTest::VT_C, c);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Particle>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StructTest::VT_D, d);
+  }
+  #[inline]
+  pub fn add_e(&mut self, e: i32) {
+    self.fbb_.push_slot::<i32>(StructTest::VT_E, e, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StructTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StructTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StructTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StructTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StructTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.field("e", &self.e());
+      ds.finish()
+  }
+}
+
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_COLOR: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_COLOR: u8 = 2;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_COLOR: [Color; 3] = [
+  Color::Red,
+  Color::Green,
+  Color::Blue,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Color(pub u8);
+#[allow(non_upper_case_globals)]
+impl Color {
+  pub const Red: Self = Self(0);
+  pub const Green: Self = Self(1);
+  pub const Blue: Self = Self(2);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 2;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Red,
+    Self::Green,
+    Self::Blue,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Red => Some("Red"),
+      Self::Green => Some("Green"),
+      Self::Blue => Some("Blue"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Color {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Color {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Color {
+    type Output = Color;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for Color {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Color {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Color {}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_LEVEL: i16 = -1;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_LEVEL: i16 = 5;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_LEVEL: [Level; 3] = [
+  Level::Low,
+  Level::Mid,
+  Level::High,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Level(pub i16);
+#[allow(non_upper_case_globals)]
+impl Level {
+  pub const Low: Self = Self(-1);
+  pub const Mid: Self = Self(0);
+  pub const High: Self = Self(5);
+
+  pub const ENUM_MIN: i16 = -1;
+  pub const ENUM_MAX: i16 = 5;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::Low,
+    Self::Mid,
+    Self::High,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::Low => Some("Low"),
+      Self::Mid => Some("Mid"),
+      Self::High => Some("High"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for Level {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for Level {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<i16>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for Level {
+    type Output = Level;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<i16>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for Level {
+  type Scalar = i16;
+  #[inline]
+  fn to_little_endian(self) -> i16 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: i16) -> Self {
+    let b = i16::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Level {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    i16::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Level {}
+// struct ColoredPoint, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct ColoredPoint(pub [u8; 8]);
+impl Default for ColoredPoint { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl core::fmt::Debug for ColoredPoint {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("ColoredPoint")
+      .field("color", &self.color())
+      .field("x", &self.x())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for ColoredPoint {}
+impl<'a> flatbuffers::Follow<'a> for ColoredPoint {
+  type Inner = &'a ColoredPoint;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a ColoredPoint>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a ColoredPoint {
+  type Inner = &'a ColoredPoint;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<ColoredPoint>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for ColoredPoint {
+    type Output = ColoredPoint;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const ColoredPoint as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for ColoredPoint {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> ColoredPoint {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    color: Color,
+    x: f32,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_color(color);
+    s.set_x(x);
+    s
+  }
+
+  pub fn color(&self) -> Color {
+    let mut mem = core::mem::MaybeUninit::<<Color as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<Color as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_color(&mut self, x: Color) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<Color as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn x(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_x(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum EnumTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct EnumTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for EnumTest<'a> {
+  type Inner = EnumTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> EnumTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+  pub const VT_E: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    EnumTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args EnumTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<EnumTest<'bldr>> {
+    let mut builder = EnumTestBuilder::new(_fbb);
+    builder.add_e(args.e);
+    if let Some(x) = args.d { builder.add_d(x); }
+    if let Some(x) = args.c { builder.add_c(x); }
+    builder.add_b(args.b);
+    builder.add_a(args.a);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Color {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Color>(EnumTest::VT_A, Some(Color::Red)).unwrap()}
+  }
+  #[inline]
+  pub fn b(&self) -> Level {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Level>(EnumTest::VT_B, Some(Level::Mid)).unwrap()}
+  }
+  #[inline]
+  pub fn c(&self) -> Option<flatbuffers::Vector<'a, Color>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Color>>>(EnumTest::VT_C, None)}
+  }
+  #[inline]
+  pub fn d(&self) -> Option<&'a ColoredPoint> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<ColoredPoint>(EnumTest::VT_D, None)}
+  }
+  #[inline]
+  pub fn e(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(EnumTest::VT_E, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for EnumTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Color>("a", Self::VT_A, false)?
+     .visit_field::<Level>("b", Self::VT_B, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Color>>>("c", Self::VT_C, false)?
+     .visit_field::<ColoredPoint>("d", Self::VT_D, false)?
+     .visit_field::<i32>("e", Self::VT_E, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EnumTestArgs<'a> {
+    pub a: Color,
+    pub b: Level,
+    pub c: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Color>>>,
+    pub d: Option<&'a ColoredPoint>,
+    pub e: i32,
+}
+impl<'a> Default for EnumTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    EnumTestArgs {
+      a: Color::Red,
+      b: Level::Mid,
+      c: None,
+      d: None,
+      e: 0,
+    }
+  }
+}
+
+pub struct EnumTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EnumTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: Color) {
+    self.fbb_.push_slot::<Color>(EnumTest::VT_A, a, Color::Red);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: Level) {
+    self.fbb_.push_slot::<Level>(EnumTest::VT_B, b, Level::Mid);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Color>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(EnumTest::VT_C, c);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: &ColoredPoint) {
+    self.fbb_.push_slot_always::<&ColoredPoint>(EnumTest::VT_D, d);
+  }
+  #[inline]
+  pub fn add_e(&mut self, e: i32) {
+    self.fbb_.push_slot::<i32>(EnumTest::VT_E, e, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EnumTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EnumTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<EnumTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for EnumTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("EnumTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.field("e", &self.e());
+      ds.finish()
+  }
+}
+
+#[allow(non_upper_case_globals)]
+mod bitflags_permissions {
+  flatbuffers::bitflags::bitflags! {
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Permissions: u8 {
+      const Read = 1;
+      const Write = 2;
+      const Execute = 4;
+    }
+  }
+}
+pub use self::bitflags_permissions::Permissions;
+
+impl<'a> flatbuffers::Follow<'a> for Permissions {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self::from_bits_retain(b)
+  }
+}
+
+impl flatbuffers::Push for Permissions {
+    type Output = Permissions;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.bits()); }
+    }
+}
+
+impl flatbuffers::EndianScalar for Permissions {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.bits().to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self::from_bits_retain(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for Permissions {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Permissions {}
+pub enum FlagsTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FlagsTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FlagsTest<'a> {
+  type Inner = FlagsTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> FlagsTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    FlagsTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args FlagsTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<FlagsTest<'bldr>> {
+    let mut builder = FlagsTestBuilder::new(_fbb);
+    builder.add_c(args.c);
+    if let Some(x) = args.b { builder.add_b(x); }
+    builder.add_a(args.a);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Permissions {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Permissions>(FlagsTest::VT_A, Some(Permissions::empty())).unwrap()}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<flatbuffers::Vector<'a, Permissions>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Permissions>>>(FlagsTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(FlagsTest::VT_C, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for FlagsTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Permissions>("a", Self::VT_A, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Permissions>>>("b", Self::VT_B, false)?
+     .visit_field::<i32>("c", Self::VT_C, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct FlagsTestArgs<'a> {
+    pub a: Permissions,
+    pub b: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Permissions>>>,
+    pub c: i32,
+}
+impl<'a> Default for FlagsTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    FlagsTestArgs {
+      a: Permissions::empty(),
+      b: None,
+      c: 0,
+    }
+  }
+}
+
+pub struct FlagsTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> FlagsTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: Permissions) {
+    self.fbb_.push_slot::<Permissions>(FlagsTest::VT_A, a, Permissions::empty());
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Permissions>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(FlagsTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: i32) {
+    self.fbb_.push_slot::<i32>(FlagsTest::VT_C, c, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> FlagsTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    FlagsTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<FlagsTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for FlagsTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("FlagsTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.finish()
+  }
+}
+
+// struct Calibration, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Calibration(pub [u8; 44]);
+impl Default for Calibration { 
+  fn default() -> Self { 
+    Self([0; 44])
+  }
+}
+impl core::fmt::Debug for Calibration {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Calibration")
+      .field("id", &self.id())
+      .field("gains", &self.gains())
+      .field("colors", &self.colors())
+      .field("points", &self.points())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Calibration {}
+impl<'a> flatbuffers::Follow<'a> for Calibration {
+  type Inner = &'a Calibration;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Calibration>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Calibration {
+  type Inner = &'a Calibration;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Calibration>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Calibration {
+    type Output = Calibration;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Calibration as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Calibration {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Calibration {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: u16,
+    gains: &[f32; 3],
+    colors: &[Color; 2],
+    points: &[Vec3; 2],
+  ) -> Self {
+    let mut s = Self([0; 44]);
+    s.set_id(id);
+    s.set_gains(gains);
+    s.set_colors(colors);
+    s.set_points(points);
+    s
+  }
+
+  pub fn id(&self) -> u16 {
+    let mut mem = core::mem::MaybeUninit::<<u16 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_id(&mut self, x: u16) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn gains(&'a self) -> flatbuffers::Array<'a, f32, 3> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 4) }
+  }
+
+  pub fn set_gains(&mut self, items: &[f32; 3]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 4, items) };
+  }
+
+  pub fn colors(&'a self) -> flatbuffers::Array<'a, Color, 2> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 16) }
+  }
+
+  pub fn set_colors(&mut self, items: &[Color; 2]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 16, items) };
+  }
+
+  pub fn points(&'a self) -> flatbuffers::Array<'a, Vec3, 2> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 20) }
+  }
+
+  pub fn set_points(&mut self, x: &[Vec3; 2]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe {
+      core::ptr::copy(
+        x.as_ptr() as *const u8,
+        self.0.as_mut_ptr().add(20),
+        24,
+      );
+    }
+  }
+
+}
+
+pub enum ArrayTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ArrayTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ArrayTest<'a> {
+  type Inner = ArrayTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ArrayTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ArrayTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ArrayTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ArrayTest<'bldr>> {
+    let mut builder = ArrayTestBuilder::new(_fbb);
+    builder.add_c(args.c);
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<&'a Calibration> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Calibration>(ArrayTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<flatbuffers::Vector<'a, Calibration>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Calibration>>>(ArrayTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(ArrayTest::VT_C, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for ArrayTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Calibration>("a", Self::VT_A, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Calibration>>>("b", Self::VT_B, false)?
+     .visit_field::<i32>("c", Self::VT_C, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ArrayTestArgs<'a> {
+    pub a: Option<&'a Calibration>,
+    pub b: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Calibration>>>,
+    pub c: i32,
+}
+impl<'a> Default for ArrayTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ArrayTestArgs {
+      a: None,
+      b: None,
+      c: 0,
+    }
+  }
+}
+
+pub struct ArrayTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArrayTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: &Calibration) {
+    self.fbb_.push_slot_always::<&Calibration>(ArrayTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Calibration>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: i32) {
+    self.fbb_.push_slot::<i32>(ArrayTest::VT_C, c, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ArrayTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ArrayTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ArrayTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ArrayTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ArrayTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.finish()
+  }
+}
+
+pub enum NodeOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Node<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Node<'a> {
+  type Inner = Node<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Node<'a> {
+  pub const VT_NAME: flatbuffers::VOffsetT = 4;
+  pub const VT_CHILDREN: flatbuffers::VOffsetT = 6;
+  pub const VT_PARENT: flatbuffers::VOffsetT = 8;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Node { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args NodeArgs<'args>
+  ) -> flatbuffers::WIPOffset<Node<'bldr>> {
+    let mut builder = NodeBuilder::new(_fbb);
+    builder.add_value(args.value);
+    if let Some(x) = args.parent { builder.add_parent(x); }
+    if let Some(x) = args.children { builder.add_children(x); }
+    if let Some(x) = args.name { builder.add_name(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn name(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(Node::VT_NAME, None)}
+  }
+  #[inline]
+  pub fn children(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node>>>>(Node::VT_CHILDREN, None)}
+  }
+  #[inline]
+  pub fn parent(&self) -> Option<Node<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Node>>(Node::VT_PARENT, None)}
+  }
+  #[inline]
+  pub fn value(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(Node::VT_VALUE, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Node<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("name", Self::VT_NAME, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<Node>>>>("children", Self::VT_CHILDREN, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Node>>("parent", Self::VT_PARENT, false)?
+     .visit_field::<i32>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct NodeArgs<'a> {
+    pub name: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub children: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<Node<'a>>>>>,
+    pub parent: Option<flatbuffers::WIPOffset<Node<'a>>>,
+    pub value: i32,
+}
+impl<'a> Default for NodeArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    NodeArgs {
+      name: None,
+      children: None,
+      parent: None,
+      value: 0,
+    }
+  }
+}
+
+pub struct NodeBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> NodeBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_name(&mut self, name: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_NAME, name);
+  }
+  #[inline]
+  pub fn add_children(&mut self, children: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<Node<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(Node::VT_CHILDREN, children);
+  }
+  #[inline]
+  pub fn add_parent(&mut self, parent: flatbuffers::WIPOffset<Node<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Node>>(Node::VT_PARENT, parent);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: i32) {
+    self.fbb_.push_slot::<i32>(Node::VT_VALUE, value, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> NodeBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    NodeBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Node<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Node<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Node");
+      ds.field("name", &self.name());
+      ds.field("children", &self.children());
+      ds.field("parent", &self.parent());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+
+pub enum StringListTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StringListTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StringListTest<'a> {
+  type Inner = StringListTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> StringListTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StringListTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StringListTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<StringListTest<'bldr>> {
+    let mut builder = StringListTestBuilder::new(_fbb);
+    builder.add_b(args.b);
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>(StringListTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(StringListTest::VT_B, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for StringListTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>>>("a", Self::VT_A, false)?
+     .visit_field::<i32>("b", Self::VT_B, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StringListTestArgs<'a> {
+    pub a: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>>,
+    pub b: i32,
+}
+impl<'a> Default for StringListTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StringListTestArgs {
+      a: None,
+      b: 0,
+    }
+  }
+}
+
+pub struct StringListTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StringListTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<&'b  str>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StringListTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: i32) {
+    self.fbb_.push_slot::<i32>(StringListTest::VT_B, b, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StringListTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StringListTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StringListTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StringListTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StringListTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.finish()
+  }
+}
+pub enum UnionListTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct UnionListTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for UnionListTest<'a> {
+  type Inner = UnionListTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> UnionListTest<'a> {
+  pub const VT_A_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_A: flatbuffers::VOffsetT = 6;
+  pub const VT_B: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    UnionListTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args UnionListTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<UnionListTest<'bldr>> {
+    let mut builder = UnionListTestBuilder::new(_fbb);
+    builder.add_b(args.b);
+    if let Some(x) = args.a { builder.add_a(x); }
+    if let Some(x) = args.a_type { builder.add_a_type(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a_type(&self) -> Option<flatbuffers::Vector<'a, TestUnion>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, TestUnion>>>(UnionListTest::VT_A_TYPE, None)}
+  }
+  #[inline]
+  pub fn a(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>>(UnionListTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(UnionListTest::VT_B, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for UnionListTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, TestUnion>>>("a_type", Self::VT_A_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::UOffsetT>>>("a", Self::VT_A, false)?
+     .visit_field::<i32>("b", Self::VT_B, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct UnionListTestArgs<'a> {
+    pub a_type: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, TestUnion>>>,
+    pub a: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>>,
+    pub b: i32,
+}
+impl<'a> Default for UnionListTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    UnionListTestArgs {
+      a_type: None,
+      a: None,
+      b: 0,
+    }
+  }
+}
+
+pub struct UnionListTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> UnionListTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a_type(&mut self, a_type: flatbuffers::WIPOffset<flatbuffers::Vector<'b , TestUnion>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(UnionListTest::VT_A_TYPE, a_type);
+  }
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<flatbuffers::Table<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(UnionListTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: i32) {
+    self.fbb_.push_slot::<i32>(UnionListTest::VT_B, b, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> UnionListTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    UnionListTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<UnionListTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for UnionListTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("UnionListTest");
+      ds.field("a_type", &self.a_type());
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.finish()
+  }
+}
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MIN_MIXED_UNION: u8 = 0;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+pub const ENUM_MAX_MIXED_UNION: u8 = 3;
+#[deprecated(since = "2.0.0", note = "Use associated constants instead. This will no longer be generated in 2021.")]
+#[allow(non_camel_case_types)]
+pub const ENUM_VALUES_MIXED_UNION: [MixedUnion; 4] = [
+  MixedUnion::NONE,
+  MixedUnion::Test,
+  MixedUnion::Vec3,
+  MixedUnion::Name,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct MixedUnion(pub u8);
+#[allow(non_upper_case_globals)]
+impl MixedUnion {
+  pub const NONE: Self = Self(0);
+  pub const Test: Self = Self(1);
+  pub const Vec3: Self = Self(2);
+  pub const Name: Self = Self(3);
+
+  pub const ENUM_MIN: u8 = 0;
+  pub const ENUM_MAX: u8 = 3;
+  pub const ENUM_VALUES: &'static [Self] = &[
+    Self::NONE,
+    Self::Test,
+    Self::Vec3,
+    Self::Name,
+  ];
+  /// Returns the variant's name or "" if unknown.
+  pub fn variant_name(self) -> Option<&'static str> {
+    match self {
+      Self::NONE => Some("NONE"),
+      Self::Test => Some("Test"),
+      Self::Vec3 => Some("Vec3"),
+      Self::Name => Some("Name"),
+      _ => None,
+    }
+  }
+}
+impl core::fmt::Debug for MixedUnion {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    if let Some(name) = self.variant_name() {
+      f.write_str(name)
+    } else {
+      f.write_fmt(format_args!("<UNKNOWN {:?}>", self.0))
+    }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for MixedUnion {
+  type Inner = Self;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    let b = unsafe { flatbuffers::read_scalar_at::<u8>(buf, loc) };
+    Self(b)
+  }
+}
+
+impl flatbuffers::Push for MixedUnion {
+    type Output = MixedUnion;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        unsafe { flatbuffers::emplace_scalar::<u8>(dst, self.0); }
+    }
+}
+
+impl flatbuffers::EndianScalar for MixedUnion {
+  type Scalar = u8;
+  #[inline]
+  fn to_little_endian(self) -> u8 {
+    self.0.to_le()
+  }
+  #[inline]
+  #[allow(clippy::wrong_self_convention)]
+  fn from_little_endian(v: u8) -> Self {
+    let b = u8::from_le(v);
+    Self(b)
+  }
+}
+
+impl<'a> flatbuffers::Verifiable for MixedUnion {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    u8::run_verifier(v, pos)
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for MixedUnion {}
+pub enum MixedUnionTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MixedUnionTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MixedUnionTest<'a> {
+  type Inner = MixedUnionTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> MixedUnionTest<'a> {
+  pub const VT_A_TYPE: flatbuffers::VOffsetT = 4;
+  pub const VT_A: flatbuffers::VOffsetT = 6;
+  pub const VT_B_TYPE: flatbuffers::VOffsetT = 8;
+  pub const VT_B: flatbuffers::VOffsetT = 10;
+  pub const VT_C: flatbuffers::VOffsetT = 12;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MixedUnionTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MixedUnionTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<MixedUnionTest<'bldr>> {
+    let mut builder = MixedUnionTestBuilder::new(_fbb);
+    builder.add_c(args.c);
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.b_type { builder.add_b_type(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.add_a_type(args.a_type);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a_type(&self) -> MixedUnion {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<MixedUnion>(MixedUnionTest::VT_A_TYPE, Some(MixedUnion::NONE)).unwrap()}
+  }
+  #[inline]
+  pub fn a(&self) -> Option<flatbuffers::Table<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>(MixedUnionTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b_type(&self) -> Option<flatbuffers::Vector<'a, MixedUnion>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, MixedUnion>>>(MixedUnionTest::VT_B_TYPE, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>>(MixedUnionTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(MixedUnionTest::VT_C, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MixedUnionTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_union::<MixedUnion, _>("a_type", Self::VT_A_TYPE, "a", Self::VT_A, false, |key, v, pos| {
+        match key {
+          MixedUnion::Test => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Test>>("MixedUnion::Test", pos),
+          MixedUnion::Vec3 => v.verify_union_variant::<flatbuffers::ForwardsUOffset<Vec3>>("MixedUnion::Vec3", pos),
+          MixedUnion::Name => v.verify_union_variant::<flatbuffers::ForwardsUOffset<&str>>("MixedUnion::Name", pos),
+          _ => Ok(()),
+        }
+     })?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, MixedUnion>>>("b_type", Self::VT_B_TYPE, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::UOffsetT>>>("b", Self::VT_B, false)?
+     .visit_field::<i32>("c", Self::VT_C, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MixedUnionTestArgs<'a> {
+    pub a_type: MixedUnion,
+    pub a: Option<flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>>,
+    pub b_type: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, MixedUnion>>>,
+    pub b: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<flatbuffers::Table<'a>>>>>,
+    pub c: i32,
+}
+impl<'a> Default for MixedUnionTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MixedUnionTestArgs {
+      a_type: MixedUnion::NONE,
+      a: None,
+      b_type: None,
+      b: None,
+      c: 0,
+    }
+  }
+}
+
+pub struct MixedUnionTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MixedUnionTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a_type(&mut self, a_type: MixedUnion) {
+    self.fbb_.push_slot::<MixedUnion>(MixedUnionTest::VT_A_TYPE, a_type, MixedUnion::NONE);
+  }
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<flatbuffers::UnionWIPOffset>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MixedUnionTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b_type(&mut self, b_type: flatbuffers::WIPOffset<flatbuffers::Vector<'b , MixedUnion>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MixedUnionTest::VT_B_TYPE, b_type);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<flatbuffers::Table<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MixedUnionTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: i32) {
+    self.fbb_.push_slot::<i32>(MixedUnionTest::VT_C, c, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MixedUnionTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MixedUnionTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MixedUnionTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MixedUnionTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MixedUnionTest");
+      ds.field("a_type", &self.a_type());
+      match self.a_type() {
+        _ => {
+          let x: Option<()> = None;
+          ds.field("a", &x)
+        },
+      };
+      ds.field("b_type", &self.b_type());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.finish()
+  }
+}
+pub enum IntEntryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct IntEntry<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for IntEntry<'a> {
+  type Inner = IntEntry<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> IntEntry<'a> {
+  pub const VT_KEY: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    IntEntry { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args IntEntryArgs<'args>
+  ) -> flatbuffers::WIPOffset<IntEntry<'bldr>> {
+    let mut builder = IntEntryBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    builder.add_key(args.key);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(IntEntry::VT_KEY, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &IntEntry) -> bool {
+    self.key() < o.key()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: i32) -> ::core::cmp::Ordering {
+    let key = self.key();
+    key.cmp(&val)
+  }
+  #[inline]
+  pub fn value(&self) -> Option<&'a str> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(IntEntry::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for IntEntry<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<i32>("key", Self::VT_KEY, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct IntEntryArgs<'a> {
+    pub key: i32,
+    pub value: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for IntEntryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    IntEntryArgs {
+      key: 0,
+      value: None,
+    }
+  }
+}
+
+pub struct IntEntryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> IntEntryBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: i32) {
+    self.fbb_.push_slot::<i32>(IntEntry::VT_KEY, key, 0);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(IntEntry::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> IntEntryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    IntEntryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<IntEntry<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for IntEntry<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("IntEntry");
+      ds.field("key", &self.key());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum StringEntryOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct StringEntry<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for StringEntry<'a> {
+  type Inner = StringEntry<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> StringEntry<'a> {
+  pub const VT_KEY: flatbuffers::VOffsetT = 4;
+  pub const VT_VALUE: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    StringEntry { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args StringEntryArgs<'args>
+  ) -> flatbuffers::WIPOffset<StringEntry<'bldr>> {
+    let mut builder = StringEntryBuilder::new(_fbb);
+    if let Some(x) = args.value { builder.add_value(x); }
+    if let Some(x) = args.key { builder.add_key(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn key(&self) -> &'a str {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<&str>>(StringEntry::VT_KEY, None).unwrap()}
+  }
+  #[inline]
+  pub fn key_compare_less_than(&self, o: &StringEntry) -> bool {
+    self.key() < o.key()
+  }
+
+  #[inline]
+  pub fn key_compare_with_value(&self, val: & str) -> ::core::cmp::Ordering {
+    let key = self.key();
+    key.cmp(val)
+  }
+  #[inline]
+  pub fn value(&self) -> Option<Test<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Test>>(StringEntry::VT_VALUE, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for StringEntry<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<&str>>("key", Self::VT_KEY, true)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Test>>("value", Self::VT_VALUE, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct StringEntryArgs<'a> {
+    pub key: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub value: Option<flatbuffers::WIPOffset<Test<'a>>>,
+}
+impl<'a> Default for StringEntryArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    StringEntryArgs {
+      key: None,
+      value: None,
+    }
+  }
+}
+
+pub struct StringEntryBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> StringEntryBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_key(&mut self, key: flatbuffers::WIPOffset<&'b  str>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(StringEntry::VT_KEY, key);
+  }
+  #[inline]
+  pub fn add_value(&mut self, value: flatbuffers::WIPOffset<Test<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Test>>(StringEntry::VT_VALUE, value);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> StringEntryBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    StringEntryBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<StringEntry<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    self.fbb_.required(o, StringEntry::VT_KEY,"key");
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for StringEntry<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("StringEntry");
+      ds.field("key", &self.key());
+      ds.field("value", &self.value());
+      ds.finish()
+  }
+}
+pub enum MapTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct MapTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for MapTest<'a> {
+  type Inner = MapTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> MapTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    MapTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args MapTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<MapTest<'bldr>> {
+    let mut builder = MapTestBuilder::new(_fbb);
+    builder.add_c(args.c);
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<IntEntry<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<IntEntry>>>>(MapTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringEntry<'a>>>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringEntry>>>>(MapTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> i32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i32>(MapTest::VT_C, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for MapTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<IntEntry>>>>("a", Self::VT_A, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<StringEntry>>>>("b", Self::VT_B, false)?
+     .visit_field::<i32>("c", Self::VT_C, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct MapTestArgs<'a> {
+    pub a: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<IntEntry<'a>>>>>,
+    pub b: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<StringEntry<'a>>>>>,
+    pub c: i32,
+}
+impl<'a> Default for MapTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    MapTestArgs {
+      a: None,
+      b: None,
+      c: 0,
+    }
+  }
+}
+
+pub struct MapTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> MapTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<IntEntry<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MapTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<flatbuffers::Vector<'b , flatbuffers::ForwardsUOffset<StringEntry<'b >>>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(MapTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: i32) {
+    self.fbb_.push_slot::<i32>(MapTest::VT_C, c, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> MapTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    MapTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<MapTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for MapTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("MapTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.finish()
+  }
+}
+// struct U128, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct U128(pub [u8; 16]);
+impl Default for U128 { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for U128 {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("U128")
+      .field("lo", &self.lo())
+      .field("hi", &self.hi())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for U128 {}
+impl<'a> flatbuffers::Follow<'a> for U128 {
+  type Inner = &'a U128;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a U128>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a U128 {
+  type Inner = &'a U128;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<U128>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for U128 {
+    type Output = U128;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const U128 as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for U128 {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> U128 {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    lo: u64,
+    hi: u64,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_lo(lo);
+    s.set_hi(hi);
+    s
+  }
+
+  pub fn lo(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_lo(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn hi(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_hi(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct Duration, aligned to 8
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Duration(pub [u8; 16]);
+impl Default for Duration { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for Duration {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Duration")
+      .field("secs", &self.secs())
+      .field("nanos", &self.nanos())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Duration {}
+impl<'a> flatbuffers::Follow<'a> for Duration {
+  type Inner = &'a Duration;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Duration>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Duration {
+  type Inner = &'a Duration;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Duration>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Duration {
+    type Output = Duration;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Duration as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(8)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Duration {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Duration {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    secs: u64,
+    nanos: u32,
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_secs(secs);
+    s.set_nanos(nanos);
+    s
+  }
+
+  pub fn secs(&self) -> u64 {
+    let mut mem = core::mem::MaybeUninit::<<u64 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_secs(&mut self, x: u64) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u64 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn nanos(&self) -> u32 {
+    let mut mem = core::mem::MaybeUninit::<<u32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[8..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_nanos(&mut self, x: u32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[8..].as_mut_ptr(),
+        core::mem::size_of::<<u32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+// struct Ipv4, aligned to 1
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ipv4(pub [u8; 4]);
+impl Default for Ipv4 { 
+  fn default() -> Self { 
+    Self([0; 4])
+  }
+}
+impl core::fmt::Debug for Ipv4 {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Ipv4")
+      .field("octets", &self.octets())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Ipv4 {}
+impl<'a> flatbuffers::Follow<'a> for Ipv4 {
+  type Inner = &'a Ipv4;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Ipv4>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Ipv4 {
+  type Inner = &'a Ipv4;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Ipv4>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Ipv4 {
+    type Output = Ipv4;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Ipv4 as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(1)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Ipv4 {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Ipv4 {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    octets: &[u8; 4],
+  ) -> Self {
+    let mut s = Self([0; 4]);
+    s.set_octets(octets);
+    s
+  }
+
+  pub fn octets(&'a self) -> flatbuffers::Array<'a, u8, 4> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 0) }
+  }
+
+  pub fn set_octets(&mut self, items: &[u8; 4]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 0, items) };
+  }
+
+}
+
+// struct Ipv6, aligned to 1
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ipv6(pub [u8; 16]);
+impl Default for Ipv6 { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for Ipv6 {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Ipv6")
+      .field("octets", &self.octets())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Ipv6 {}
+impl<'a> flatbuffers::Follow<'a> for Ipv6 {
+  type Inner = &'a Ipv6;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Ipv6>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Ipv6 {
+  type Inner = &'a Ipv6;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Ipv6>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Ipv6 {
+    type Output = Ipv6;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Ipv6 as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(1)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Ipv6 {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Ipv6 {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    octets: &[u8; 16],
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_octets(octets);
+    s
+  }
+
+  pub fn octets(&'a self) -> flatbuffers::Array<'a, u8, 16> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 0) }
+  }
+
+  pub fn set_octets(&mut self, items: &[u8; 16]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 0, items) };
+  }
+
+}
+
+pub enum ValueTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ValueTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ValueTest<'a> {
+  type Inner = ValueTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> ValueTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+  pub const VT_E: flatbuffers::VOffsetT = 12;
+  pub const VT_F: flatbuffers::VOffsetT = 14;
+  pub const VT_G: flatbuffers::VOffsetT = 16;
+  pub const VT_H: flatbuffers::VOffsetT = 18;
+  pub const VT_I: flatbuffers::VOffsetT = 20;
+  pub const VT_J: flatbuffers::VOffsetT = 22;
+  pub const VT_K: flatbuffers::VOffsetT = 24;
+  pub const VT_L: flatbuffers::VOffsetT = 26;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    ValueTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args ValueTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<ValueTest<'bldr>> {
+    let mut builder = ValueTestBuilder::new(_fbb);
+    if let Some(x) = args.l { builder.add_l(x); }
+    if let Some(x) = args.k { builder.add_k(x); }
+    if let Some(x) = args.j { builder.add_j(x); }
+    if let Some(x) = args.i { builder.add_i(x); }
+    if let Some(x) = args.h { builder.add_h(x); }
+    if let Some(x) = args.g { builder.add_g(x); }
+    if let Some(x) = args.f { builder.add_f(x); }
+    if let Some(x) = args.e { builder.add_e(x); }
+    if let Some(x) = args.d { builder.add_d(x); }
+    builder.add_c(args.c);
+    builder.add_a(args.a);
+    builder.add_b(args.b);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ValueTest::VT_A, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn b(&self) -> i16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i16>(ValueTest::VT_B, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn c(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(ValueTest::VT_C, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn d(&self) -> Option<&'a U128> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<U128>(ValueTest::VT_D, None)}
+  }
+  #[inline]
+  pub fn e(&self) -> Option<&'a U128> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<U128>(ValueTest::VT_E, None)}
+  }
+  #[inline]
+  pub fn f(&self) -> Option<&'a Duration> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Duration>(ValueTest::VT_F, None)}
+  }
+  #[inline]
+  pub fn g(&self) -> Option<&'a Ipv4> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Ipv4>(ValueTest::VT_G, None)}
+  }
+  #[inline]
+  pub fn h(&self) -> Option<&'a Ipv6> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Ipv6>(ValueTest::VT_H, None)}
+  }
+  #[inline]
+  pub fn i(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ValueTest::VT_I, None)}
+  }
+  #[inline]
+  pub fn j(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ValueTest::VT_J, None)}
+  }
+  #[inline]
+  pub fn k(&self) -> Option<flatbuffers::Vector<'a, u32>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u32>>>(ValueTest::VT_K, None)}
+  }
+  #[inline]
+  pub fn l(&self) -> Option<flatbuffers::Vector<'a, Duration>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Duration>>>(ValueTest::VT_L, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for ValueTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("a", Self::VT_A, false)?
+     .visit_field::<i16>("b", Self::VT_B, false)?
+     .visit_field::<u32>("c", Self::VT_C, false)?
+     .visit_field::<U128>("d", Self::VT_D, false)?
+     .visit_field::<U128>("e", Self::VT_E, false)?
+     .visit_field::<Duration>("f", Self::VT_F, false)?
+     .visit_field::<Ipv4>("g", Self::VT_G, false)?
+     .visit_field::<Ipv6>("h", Self::VT_H, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("i", Self::VT_I, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("j", Self::VT_J, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u32>>>("k", Self::VT_K, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Duration>>>("l", Self::VT_L, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct ValueTestArgs<'a> {
+    pub a: u32,
+    pub b: i16,
+    pub c: u32,
+    pub d: Option<&'a U128>,
+    pub e: Option<&'a U128>,
+    pub f: Option<&'a Duration>,
+    pub g: Option<&'a Ipv4>,
+    pub h: Option<&'a Ipv6>,
+    pub i: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub j: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub k: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u32>>>,
+    pub l: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Duration>>>,
+}
+impl<'a> Default for ValueTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    ValueTestArgs {
+      a: 0,
+      b: 0,
+      c: 0,
+      d: None,
+      e: None,
+      f: None,
+      g: None,
+      h: None,
+      i: None,
+      j: None,
+      k: None,
+      l: None,
+    }
+  }
+}
+
+pub struct ValueTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ValueTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: u32) {
+    self.fbb_.push_slot::<u32>(ValueTest::VT_A, a, 0);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: i16) {
+    self.fbb_.push_slot::<i16>(ValueTest::VT_B, b, 0);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: u32) {
+    self.fbb_.push_slot::<u32>(ValueTest::VT_C, c, 0);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: &U128) {
+    self.fbb_.push_slot_always::<&U128>(ValueTest::VT_D, d);
+  }
+  #[inline]
+  pub fn add_e(&mut self, e: &U128) {
+    self.fbb_.push_slot_always::<&U128>(ValueTest::VT_E, e);
+  }
+  #[inline]
+  pub fn add_f(&mut self, f: &Duration) {
+    self.fbb_.push_slot_always::<&Duration>(ValueTest::VT_F, f);
+  }
+  #[inline]
+  pub fn add_g(&mut self, g: &Ipv4) {
+    self.fbb_.push_slot_always::<&Ipv4>(ValueTest::VT_G, g);
+  }
+  #[inline]
+  pub fn add_h(&mut self, h: &Ipv6) {
+    self.fbb_.push_slot_always::<&Ipv6>(ValueTest::VT_H, h);
+  }
+  #[inline]
+  pub fn add_i(&mut self, i: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ValueTest::VT_I, i);
+  }
+  #[inline]
+  pub fn add_j(&mut self, j: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ValueTest::VT_J, j);
+  }
+  #[inline]
+  pub fn add_k(&mut self, k: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u32>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ValueTest::VT_K, k);
+  }
+  #[inline]
+  pub fn add_l(&mut self, l: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Duration>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ValueTest::VT_L, l);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ValueTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    ValueTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<ValueTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for ValueTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("ValueTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.field("e", &self.e());
+      ds.field("f", &self.f());
+      ds.field("g", &self.g());
+      ds.field("h", &self.h());
+      ds.field("i", &self.i());
+      ds.field("j", &self.j());
+      ds.field("k", &self.k());
+      ds.field("l", &self.l());
+      ds.finish()
+  }
+}
+// struct Uuid, aligned to 1
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Uuid(pub [u8; 16]);
+impl Default for Uuid { 
+  fn default() -> Self { 
+    Self([0; 16])
+  }
+}
+impl core::fmt::Debug for Uuid {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Uuid")
+      .field("bytes", &self.bytes())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Uuid {}
+impl<'a> flatbuffers::Follow<'a> for Uuid {
+  type Inner = &'a Uuid;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Uuid>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Uuid {
+  type Inner = &'a Uuid;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Uuid>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Uuid {
+    type Output = Uuid;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Uuid as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(1)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Uuid {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Uuid {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    bytes: &[u8; 16],
+  ) -> Self {
+    let mut s = Self([0; 16]);
+    s.set_bytes(bytes);
+    s
+  }
+
+  pub fn bytes(&'a self) -> flatbuffers::Array<'a, u8, 16> {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::Array::follow(&self.0, 0) }
+  }
+
+  pub fn set_bytes(&mut self, items: &[u8; 16]) {
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid array in this slot
+    unsafe { flatbuffers::emplace_scalar_array(&mut self.0, 0, items) };
+  }
+
+}
+
+pub enum TimestampTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct TimestampTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for TimestampTest<'a> {
+  type Inner = TimestampTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> TimestampTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    TimestampTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args TimestampTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<TimestampTest<'bldr>> {
+    let mut builder = TimestampTestBuilder::new(_fbb);
+    builder.add_c(args.c);
+    builder.add_b(args.b);
+    if let Some(x) = args.d { builder.add_d(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<&'a Uuid> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Uuid>(TimestampTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> i64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(TimestampTest::VT_B, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn c(&self) -> i64 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<i64>(TimestampTest::VT_C, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn d(&self) -> Option<flatbuffers::Vector<'a, Uuid>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Uuid>>>(TimestampTest::VT_D, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for TimestampTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<Uuid>("a", Self::VT_A, false)?
+     .visit_field::<i64>("b", Self::VT_B, false)?
+     .visit_field::<i64>("c", Self::VT_C, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Uuid>>>("d", Self::VT_D, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct TimestampTestArgs<'a> {
+    pub a: Option<&'a Uuid>,
+    pub b: i64,
+    pub c: i64,
+    pub d: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Uuid>>>,
+}
+impl<'a> Default for TimestampTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    TimestampTestArgs {
+      a: None,
+      b: 0,
+      c: 0,
+      d: None,
+    }
+  }
+}
+
+pub struct TimestampTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> TimestampTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: &Uuid) {
+    self.fbb_.push_slot_always::<&Uuid>(TimestampTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: i64) {
+    self.fbb_.push_slot::<i64>(TimestampTest::VT_B, b, 0);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: i64) {
+    self.fbb_.push_slot::<i64>(TimestampTest::VT_C, c, 0);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Uuid>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(TimestampTest::VT_D, d);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> TimestampTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    TimestampTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<TimestampTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for TimestampTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("TimestampTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.finish()
+  }
+}
+// struct Sample, aligned to 4
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Sample(pub [u8; 8]);
+impl Default for Sample { 
+  fn default() -> Self { 
+    Self([0; 8])
+  }
+}
+impl core::fmt::Debug for Sample {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("Sample")
+      .field("id", &self.id())
+      .field("value", &self.value())
+      .finish()
+  }
+}
+
+impl flatbuffers::SimpleToVerifyInSlice for Sample {}
+impl<'a> flatbuffers::Follow<'a> for Sample {
+  type Inner = &'a Sample;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { <&'a Sample>::follow(buf, loc) }
+  }
+}
+impl<'a> flatbuffers::Follow<'a> for &'a Sample {
+  type Inner = &'a Sample;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    unsafe { flatbuffers::follow_cast_ref::<Sample>(buf, loc) }
+  }
+}
+impl<'b> flatbuffers::Push for Sample {
+    type Output = Sample;
+    #[inline]
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        let src = unsafe { ::core::slice::from_raw_parts(self as *const Sample as *const u8, <Self as flatbuffers::Push>::size()) };
+        dst.copy_from_slice(src);
+    }
+    #[inline]
+    fn alignment() -> flatbuffers::PushAlignment {
+        flatbuffers::PushAlignment::new(4)
+    }
+}
+
+impl<'a> flatbuffers::Verifiable for Sample {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.in_buffer::<Self>(pos)
+  }
+}
+
+impl<'a> Sample {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    id: u16,
+    value: f32,
+  ) -> Self {
+    let mut s = Self([0; 8]);
+    s.set_id(id);
+    s.set_value(value);
+    s
+  }
+
+  pub fn id(&self) -> u16 {
+    let mut mem = core::mem::MaybeUninit::<<u16 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[0..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_id(&mut self, x: u16) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[0..].as_mut_ptr(),
+        core::mem::size_of::<<u16 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+  pub fn value(&self) -> f32 {
+    let mut mem = core::mem::MaybeUninit::<<f32 as EndianScalar>::Scalar>::uninit();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    EndianScalar::from_little_endian(unsafe {
+      core::ptr::copy_nonoverlapping(
+        self.0[4..].as_ptr(),
+        mem.as_mut_ptr() as *mut u8,
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+      mem.assume_init()
+    })
+  }
+
+  pub fn set_value(&mut self, x: f32) {
+    let x_le = x.to_little_endian();
+    // Safety:
+    // Created from a valid Table for this object
+    // Which contains a valid value in this slot
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &x_le as *const _ as *const u8,
+        self.0[4..].as_mut_ptr(),
+        core::mem::size_of::<<f32 as EndianScalar>::Scalar>(),
+      );
+    }
+  }
+
+}
+
+pub enum AckOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Ack<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Ack<'a> {
+  type Inner = Ack<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Ack<'a> {
+  pub const VT_SEQ: flatbuffers::VOffsetT = 4;
+  pub const VT_WINDOW: flatbuffers::VOffsetT = 6;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Ack { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args AckArgs
+  ) -> flatbuffers::WIPOffset<Ack<'bldr>> {
+    let mut builder = AckBuilder::new(_fbb);
+    builder.add_seq(args.seq);
+    builder.add_window(args.window);
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn seq(&self) -> u32 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u32>(Ack::VT_SEQ, Some(0)).unwrap()}
+  }
+  #[inline]
+  pub fn window(&self) -> u16 {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u16>(Ack::VT_WINDOW, Some(0)).unwrap()}
+  }
+}
+
+impl flatbuffers::Verifiable for Ack<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<u32>("seq", Self::VT_SEQ, false)?
+     .visit_field::<u16>("window", Self::VT_WINDOW, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct AckArgs {
+    pub seq: u32,
+    pub window: u16,
+}
+impl<'a> Default for AckArgs {
+  #[inline]
+  fn default() -> Self {
+    AckArgs {
+      seq: 0,
+      window: 0,
+    }
+  }
+}
+
+pub struct AckBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> AckBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_seq(&mut self, seq: u32) {
+    self.fbb_.push_slot::<u32>(Ack::VT_SEQ, seq, 0);
+  }
+  #[inline]
+  pub fn add_window(&mut self, window: u16) {
+    self.fbb_.push_slot::<u16>(Ack::VT_WINDOW, window, 0);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> AckBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    AckBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Ack<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Ack<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Ack");
+      ds.field("seq", &self.seq());
+      ds.field("window", &self.window());
+      ds.finish()
+  }
+}
+pub enum EmptyOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct Empty<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Empty<'a> {
+  type Inner = Empty<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> Empty<'a> {
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    Empty { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    _args: &'args EmptyArgs
+  ) -> flatbuffers::WIPOffset<Empty<'bldr>> {
+    let mut builder = EmptyBuilder::new(_fbb);
+    builder.finish()
+  }
+
+
+}
+
+impl flatbuffers::Verifiable for Empty<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct EmptyArgs {
+}
+impl<'a> Default for EmptyArgs {
+  #[inline]
+  fn default() -> Self {
+    EmptyArgs {
+    }
+  }
+}
+
+pub struct EmptyBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> EmptyBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> EmptyBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    EmptyBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<Empty<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for Empty<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("Empty");
+      ds.finish()
+  }
+}
+pub enum PositionalTestOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PositionalTest<'a> {
+  pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PositionalTest<'a> {
+  type Inner = PositionalTest<'a>;
+  #[inline]
+  unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+    Self { _tab: unsafe { flatbuffers::Table::new(buf, loc) } }
+  }
+}
+
+impl<'a> PositionalTest<'a> {
+  pub const VT_A: flatbuffers::VOffsetT = 4;
+  pub const VT_B: flatbuffers::VOffsetT = 6;
+  pub const VT_C: flatbuffers::VOffsetT = 8;
+  pub const VT_D: flatbuffers::VOffsetT = 10;
+
+  #[inline]
+  pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+    PositionalTest { _tab: table }
+  }
+  #[allow(unused_mut)]
+  pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr, A: flatbuffers::Allocator + 'bldr>(
+    _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr, A>,
+    args: &'args PositionalTestArgs<'args>
+  ) -> flatbuffers::WIPOffset<PositionalTest<'bldr>> {
+    let mut builder = PositionalTestBuilder::new(_fbb);
+    if let Some(x) = args.d { builder.add_d(x); }
+    if let Some(x) = args.c { builder.add_c(x); }
+    if let Some(x) = args.b { builder.add_b(x); }
+    if let Some(x) = args.a { builder.add_a(x); }
+    builder.finish()
+  }
+
+
+  #[inline]
+  pub fn a(&self) -> Option<Ack<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Ack>>(PositionalTest::VT_A, None)}
+  }
+  #[inline]
+  pub fn b(&self) -> Option<Empty<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<Empty>>(PositionalTest::VT_B, None)}
+  }
+  #[inline]
+  pub fn c(&self) -> Option<&'a Sample> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<Sample>(PositionalTest::VT_C, None)}
+  }
+  #[inline]
+  pub fn d(&self) -> Option<flatbuffers::Vector<'a, Sample>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, Sample>>>(PositionalTest::VT_D, None)}
+  }
+}
+
+impl flatbuffers::Verifiable for PositionalTest<'_> {
+  #[inline]
+  fn run_verifier(
+    v: &mut flatbuffers::Verifier, pos: usize
+  ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+    use self::flatbuffers::Verifiable;
+    v.visit_table(pos)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Ack>>("a", Self::VT_A, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<Empty>>("b", Self::VT_B, false)?
+     .visit_field::<Sample>("c", Self::VT_C, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, Sample>>>("d", Self::VT_D, false)?
+     .finish();
+    Ok(())
+  }
+}
+pub struct PositionalTestArgs<'a> {
+    pub a: Option<flatbuffers::WIPOffset<Ack<'a>>>,
+    pub b: Option<flatbuffers::WIPOffset<Empty<'a>>>,
+    pub c: Option<&'a Sample>,
+    pub d: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, Sample>>>,
+}
+impl<'a> Default for PositionalTestArgs<'a> {
+  #[inline]
+  fn default() -> Self {
+    PositionalTestArgs {
+      a: None,
+      b: None,
+      c: None,
+      d: None,
+    }
+  }
+}
+
+pub struct PositionalTestBuilder<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> {
+  fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a, A>,
+  start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> PositionalTestBuilder<'a, 'b, A> {
+  #[inline]
+  pub fn add_a(&mut self, a: flatbuffers::WIPOffset<Ack<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Ack>>(PositionalTest::VT_A, a);
+  }
+  #[inline]
+  pub fn add_b(&mut self, b: flatbuffers::WIPOffset<Empty<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<Empty>>(PositionalTest::VT_B, b);
+  }
+  #[inline]
+  pub fn add_c(&mut self, c: &Sample) {
+    self.fbb_.push_slot_always::<&Sample>(PositionalTest::VT_C, c);
+  }
+  #[inline]
+  pub fn add_d(&mut self, d: flatbuffers::WIPOffset<flatbuffers::Vector<'b , Sample>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(PositionalTest::VT_D, d);
+  }
+  #[inline]
+  pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> PositionalTestBuilder<'a, 'b, A> {
+    let start = _fbb.start_table();
+    PositionalTestBuilder {
+      fbb_: _fbb,
+      start_: start,
+    }
+  }
+  #[inline]
+  pub fn finish(self) -> flatbuffers::WIPOffset<PositionalTest<'a>> {
+    let o = self.fbb_.end_table(self.start_);
+    flatbuffers::WIPOffset::new(o.value())
+  }
+}
+
+impl core::fmt::Debug for PositionalTest<'_> {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let mut ds = f.debug_struct("PositionalTest");
+      ds.field("a", &self.a());
+      ds.field("b", &self.b());
+      ds.field("c", &self.c());
+      ds.field("d", &self.d());
+      ds.finish()
+  }
+}
 #[inline]
 /// Verifies that a buffer of bytes contains a `Test`
 /// and returns it.
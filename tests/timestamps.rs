@@ -0,0 +1,122 @@
+#![cfg(any(feature = "uuid", feature = "chrono", feature = "time"))]
+
+use femtoflatbuffers::{Decoder, Table};
+use femtoflatbuffers::table::Table;
+
+// Any mix of ids and times, so the same schema covers every integration
+#[derive(Table, Debug, PartialEq)]
+struct TimestampTest<A, B, C, D> {
+    a: A,
+    b: B,
+    c: C,
+    d: D
+}
+
+// Written from an iterator over the ids, which there's no decoding
+#[derive(Table)]
+#[femto(encode_only)]
+struct TimestampIterTest<A, B, C, D> {
+    a: A,
+    b: B,
+    c: C,
+    d: D
+}
+
+mod common;
+use common::{test, encode};
+
+const ID: [u8; 16] = [0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8];
+
+// 2024-02-29T12:34:56.789012345Z, and a day and a bit
+#[allow(dead_code)]
+const TIMESTAMP: i64 = 1_709_210_096_789_012_345;
+#[allow(dead_code)]
+const ELAPSED: i64 = 86_400_000_000_001;
+
+#[allow(dead_code)]
+fn build_timestamp_test(b: i64, c: i64) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let d = builder.create_vector(&[test::test::Uuid::new(&ID), test::test::Uuid::new(&[0; 16])]);
+    let table = test::test::TimestampTest::create(&mut builder, &test::test::TimestampTestArgs{
+        a: Some(&test::test::Uuid::new(&ID)),
+        b,
+        c,
+        d: Some(d)
+    });
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_test() {
+    let id = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    let test = TimestampIterTest{a: id, b: 1i64, c: 2i64, d: femtoflatbuffers::VectorIter::new([id, uuid::Uuid::nil()].into_iter())};
+    let encoded = encode(&test);
+    let decoded_test = flatbuffers::root::<test::test::TimestampTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a().unwrap().bytes().iter().collect::<Vec<_>>(), ID);
+    assert_eq!(decoded_test.d().unwrap().get(0).bytes().iter().collect::<Vec<_>>(), ID);
+
+    let encoded_test = build_timestamp_test(1, 2);
+    let decoded_test = TimestampTest::<uuid::Uuid, i64, i64, femtoflatbuffers::Vector<uuid::Uuid>>::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.a, id);
+    assert_eq!(decoded_test.d.iter().collect::<Result<Vec<_>, _>>().unwrap(), vec![id, uuid::Uuid::nil()]);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_test() {
+    use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+    use femtoflatbuffers::table::TableEncode;
+
+    let timestamp = DateTime::parse_from_rfc3339("2024-02-29T12:34:56.789012345Z").unwrap().to_utc();
+    let elapsed = TimeDelta::days(1) + TimeDelta::nanoseconds(1);
+    let test = TimestampIterTest{a: ID, b: timestamp, c: elapsed, d: femtoflatbuffers::VectorIter::new([ID, [0; 16]].into_iter())};
+    let encoded = encode(&test);
+    let decoded_test = flatbuffers::root::<test::test::TimestampTest>(&encoded).unwrap();
+    assert_eq!((decoded_test.b(), decoded_test.c()), (TIMESTAMP, ELAPSED));
+
+    let encoded_test = build_timestamp_test(TIMESTAMP, -ELAPSED);
+    let decoded_test = TimestampTest::<[u8; 16], DateTime<Utc>, TimeDelta, femtoflatbuffers::Vector<[u8; 16]>>::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!((decoded_test.b, decoded_test.c), (timestamp, -elapsed));
+    let decoded_test = TimestampTest::<[u8; 16], NaiveDateTime, TimeDelta, femtoflatbuffers::Vector<[u8; 16]>>::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.b, timestamp.naive_utc());
+
+    // Past the year 2262 doesn't fit in a long of nanoseconds
+    let test = TimestampIterTest{a: ID, b: DateTime::<Utc>::MAX_UTC, c: elapsed, d: femtoflatbuffers::VectorIter::new([ID].into_iter())};
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    assert!(matches!(test.encode(&mut encoder), Err(femtoflatbuffers::EncodeError::InvalidValue)));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_test() {
+    use femtoflatbuffers::table::TableEncode;
+    use time::{Duration, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+    let timestamp = OffsetDateTime::from_unix_timestamp_nanos(TIMESTAMP.into()).unwrap();
+    let elapsed = Duration::days(1) + Duration::nanoseconds(1);
+    // The offset isn't kept, only the instant
+    let test = TimestampIterTest{
+        a: ID,
+        b: timestamp.to_offset(UtcOffset::from_hms(-5, 0, 0).unwrap()),
+        c: elapsed,
+        d: femtoflatbuffers::VectorIter::new([ID, [0; 16]].into_iter())
+    };
+    let encoded = encode(&test);
+    let decoded_test = flatbuffers::root::<test::test::TimestampTest>(&encoded).unwrap();
+    assert_eq!((decoded_test.b(), decoded_test.c()), (TIMESTAMP, ELAPSED));
+
+    let encoded_test = build_timestamp_test(TIMESTAMP, -ELAPSED);
+    let decoded_test = TimestampTest::<[u8; 16], OffsetDateTime, Duration, femtoflatbuffers::Vector<[u8; 16]>>::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!((decoded_test.b, decoded_test.c), (timestamp, -elapsed));
+    assert_eq!(decoded_test.b.offset(), UtcOffset::UTC);
+    let decoded_test = TimestampTest::<[u8; 16], PrimitiveDateTime, Duration, femtoflatbuffers::Vector<[u8; 16]>>::decode(&Decoder::new(&encoded_test)).unwrap();
+    assert_eq!(decoded_test.b, PrimitiveDateTime::new(timestamp.date(), timestamp.time()));
+
+    let test = TimestampIterTest{a: ID, b: timestamp, c: Duration::MAX, d: femtoflatbuffers::VectorIter::new([ID].into_iter())};
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    assert!(matches!(test.encode(&mut encoder), Err(femtoflatbuffers::EncodeError::InvalidValue)));
+}
@@ -0,0 +1,260 @@
+#![cfg(feature = "alloc")]
+
+use femtoflatbuffers::{Decoder, RawTable, Table, Union};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: u32,
+    b: u32,
+    c: u32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct Test2 {
+    d: u32,
+    e: u32,
+    f: u32
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum TestUnion {
+    NONE,
+    A(Test),
+    B(Test2)
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct UnionListTest {
+    a: Vec<TestUnion>,
+    b: u32
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug)]
+enum OpenTestUnion<'a> {
+    NONE,
+    A(Test),
+    #[femto(unknown)]
+    Unknown(u8, RawTable<'a>)
+}
+
+#[derive(Table, Debug)]
+struct OpenUnionListTest<'a> {
+    a: Vec<OpenTestUnion<'a>>,
+    b: u32
+}
+
+// What a newer sender has, with a member the open union doesn't know that refers to everything a table can
+#[derive(Table, Debug, PartialEq)]
+struct Reading {
+    id: u32,
+    name: String,
+    samples: Vec<Test>,
+    tags: Vec<String>,
+    extra: Test2
+}
+
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug, PartialEq)]
+enum NewerTestUnion {
+    NONE,
+    A(Test),
+    B(Test2),
+    C(Reading)
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct NewerUnionListTest {
+    a: Vec<NewerTestUnion>,
+    b: u32
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Table, Debug, PartialEq)]
+struct HeaplessUnionListTest {
+    a: heapless::vec::Vec<TestUnion, 4>,
+    b: u32
+}
+
+mod common;
+use common::{test, encode};
+
+fn build_union_list_test() -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let mut table_builder = test::test::TestBuilder::new(&mut builder);
+    table_builder.add_a(1);
+    table_builder.add_b(2);
+    table_builder.add_c(3);
+    let first = flatbuffers::WIPOffset::new(table_builder.finish().value());
+    let mut table_builder = test::test::Test2Builder::new(&mut builder);
+    table_builder.add_d(4);
+    table_builder.add_e(5);
+    table_builder.add_f(6);
+    let second = flatbuffers::WIPOffset::new(table_builder.finish().value());
+    let a_type = builder.create_vector(&[test::test::TestUnion::A, test::test::TestUnion::B]);
+    let a = builder.create_vector(&[first, second]);
+    let mut table_builder = test::test::UnionListTestBuilder::new(&mut builder);
+    table_builder.add_a_type(a_type);
+    table_builder.add_a(a);
+    table_builder.add_b(7);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+fn check_union_list_test(encoded: &[u8]) {
+    let decoded_test = flatbuffers::root::<test::test::UnionListTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    let a_type = decoded_test.a_type().unwrap();
+    assert_eq!(a_type.iter().collect::<Vec<_>>(), vec![test::test::TestUnion::A, test::test::TestUnion::B]);
+    let a = decoded_test.a().unwrap();
+    let first = unsafe { test::test::Test::init_from_table(a.get(0)) };
+    assert_eq!((first.a(), first.b(), first.c()), (1, 2, 3));
+    let second = unsafe { test::test::Test2::init_from_table(a.get(1)) };
+    assert_eq!((second.d(), second.e(), second.f()), (4, 5, 6));
+    assert_eq!(decoded_test.b(), 7);
+}
+
+#[test]
+fn list_encode_test() {
+    let test = UnionListTest{
+        a: vec![TestUnion::A(Test{a: 1, b: 2, c: 3}), TestUnion::B(Test2{d: 4, e: 5, f: 6})],
+        b: 7
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    check_union_list_test(encoded);
+
+    let decoded_test = UnionListTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[test]
+fn list_decode_test() {
+    let encoded_test = build_union_list_test();
+    println!("{:x?}", encoded_test);
+    let decoded_test = UnionListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test, UnionListTest{
+        a: vec![TestUnion::A(Test{a: 1, b: 2, c: 3}), TestUnion::B(Test2{d: 4, e: 5, f: 6})],
+        b: 7
+    });
+}
+
+#[test]
+fn empty_list_test() {
+    let test = UnionListTest{a: vec![], b: 7};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+
+    let decoded_test = flatbuffers::root::<test::test::UnionListTest>(encoded).unwrap();
+    assert!(decoded_test.a_type().is_none());
+    assert!(decoded_test.a().is_none());
+    assert_eq!(decoded_test.b(), 7);
+
+    let decoded_test = UnionListTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn heapless_list_test() {
+    let mut a = heapless::vec::Vec::new();
+    a.push(TestUnion::A(Test{a: 1, b: 2, c: 3})).unwrap();
+    a.push(TestUnion::B(Test2{d: 4, e: 5, f: 6})).unwrap();
+    let test = HeaplessUnionListTest{a, b: 7};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    check_union_list_test(encoder.done());
+
+    let decoded_test = HeaplessUnionListTest::decode(&Decoder::new(&build_union_list_test())).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[test]
+fn unknown_list_test() {
+    let encoded_test = build_union_list_test();
+    let decoded_test = OpenUnionListTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert!(matches!(decoded_test.a[0], OpenTestUnion::A(Test{a: 1, b: 2, c: 3})));
+    assert!(matches!(decoded_test.a[1], OpenTestUnion::Unknown(2, _)));
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    check_union_list_test(encoder.done());
+}
+
+#[test]
+fn unknown_list_size_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let members = (0..50).map(|idx| {
+        let mut table_builder = test::test::Test2Builder::new(&mut builder);
+        table_builder.add_d(idx);
+        table_builder.add_e(idx + 1);
+        table_builder.add_f(idx + 2);
+        flatbuffers::WIPOffset::new(table_builder.finish().value())
+    }).collect::<Vec<_>>();
+    let a_type = builder.create_vector(&[test::test::TestUnion::B; 50]);
+    let a = builder.create_vector(&members);
+    let mut table_builder = test::test::UnionListTestBuilder::new(&mut builder);
+    table_builder.add_a_type(a_type);
+    table_builder.add_a(a);
+    table_builder.add_b(7);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    let relay = |encoded: &[u8]| {
+        let decoded_test = OpenUnionListTest::decode(&Decoder::new(encoded)).unwrap();
+        let mut buffer = vec![0u8; 64 * 1024];
+        let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+        decoded_test.encode(&mut encoder).unwrap();
+        encoder.done().to_vec()
+    };
+    let encoded = relay(encoded_test);
+
+    // Each member only brings what it refers to, or whatever its values look like they refer to, rather than all
+    // of the input, and the same again the next time
+    assert!(encoded.len() < 4 * encoded_test.len(), "{} bytes from {}", encoded.len(), encoded_test.len());
+    assert_eq!(relay(&encoded).len(), encoded.len());
+    let decoded_test = flatbuffers::root::<test::test::UnionListTest>(&encoded).unwrap();
+    let a = decoded_test.a().unwrap();
+    for idx in 0..50 {
+        let member = unsafe { test::test::Test2::init_from_table(a.get(idx)) };
+        assert_eq!((member.d(), member.e(), member.f()), (idx as i32, idx as i32 + 1, idx as i32 + 2));
+    }
+}
+
+#[test]
+fn unknown_list_relay_test() {
+    let reading = Reading{id: 1, name: "temperature".to_string(), samples: vec![Test{a: 2, b: 3, c: 4}, Test{a: 5, b: 6, c: 7}], tags: vec!["a".to_string(), "bc".to_string()], extra: Test2{d: 8, e: 9, f: 10}};
+    let test = NewerUnionListTest{a: vec![NewerTestUnion::C(reading), NewerTestUnion::A(Test{a: 11, b: 12, c: 13}), NewerTestUnion::B(Test2{d: 14, e: 15, f: 16})], b: 17};
+    let sent = encode(&test);
+    let mut encoded = sent.clone();
+
+    // Passing it on again and again doesn't make it any bigger
+    let mut sizes = vec![];
+    for _ in 0..5 {
+        let decoded_test = OpenUnionListTest::decode(&Decoder::new(&encoded)).unwrap();
+        assert!(matches!(decoded_test.a[..], [OpenTestUnion::Unknown(3, _), OpenTestUnion::A(Test{a: 11, b: 12, c: 13}), OpenTestUnion::Unknown(2, _)]));
+        encoded = encode(&decoded_test);
+        sizes.push(encoded.len());
+    }
+    assert!(sizes.iter().all(|size| *size == sizes[0]), "{:?}", sizes);
+    // Only the tables get another copy of their vtable
+    assert!(sizes[0] <= sent.len() + 64, "{} bytes from {}", sizes[0], sent.len());
+
+    assert_eq!(NewerUnionListTest::decode(&Decoder::new(&encoded)).unwrap(), test);
+}
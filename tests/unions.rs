@@ -1,4 +1,4 @@
-use femtoflatbuffers::{Decoder, Table, Union};
+use femtoflatbuffers::{Decoder, RawTable, Table, Union};
 use femtoflatbuffers::table::Table;
 
 #[derive(Table, Debug)]
@@ -15,6 +15,7 @@ struct Test2 {
     f: u32
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Union, Debug)]
 enum TestUnion {
     NONE,
@@ -28,9 +29,64 @@ struct UnionTest {
     b: u32
 }
 
-#[allow(dead_code, unused_imports)]
-#[path = "test_generated.rs"]
-mod test;
+#[derive(Table, Debug)]
+struct OptionalUnionTest {
+    a: Option<TestUnion>,
+    b: u32
+}
+
+// The absent state doesn't have to come first when it's marked
+#[allow(dead_code)]
+#[derive(Union, Debug)]
+enum MarkedUnion {
+    A(Test),
+    B(Test2),
+    #[femto(none)]
+    Empty
+}
+
+#[derive(Table, Debug)]
+struct MarkedUnionTest {
+    a: MarkedUnion,
+    b: u32
+}
+
+// Ids don't depend on the declaration order
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug)]
+enum ReorderedUnion {
+    #[femto(id = 2)]
+    B(Test2),
+    #[femto(id = 1)]
+    A(Test),
+    #[femto(none)]
+    NONE
+}
+
+#[derive(Table, Debug)]
+struct ReorderedUnionTest {
+    a: ReorderedUnion,
+    b: u32
+}
+
+// Only knows about A, anything else is kept as is
+#[allow(dead_code, clippy::upper_case_acronyms)]
+#[derive(Union, Debug)]
+enum OpenTestUnion<'a> {
+    NONE,
+    A(Test),
+    #[femto(unknown)]
+    Unknown(u8, RawTable<'a>)
+}
+
+#[derive(Table, Debug)]
+struct OpenUnionTest<'a> {
+    a: OpenTestUnion<'a>,
+    b: u32
+}
+
+mod common;
+use common::{test, encode};
 
 #[test]
 fn encode_test() {
@@ -49,7 +105,7 @@ fn encode_test() {
     let encoded = encoder.done();
     println!("{:x?}", encoded);
 
-    let decoded_test = flatbuffers::root::<test::test::UnionTest>(&encoded).unwrap();
+    let decoded_test = flatbuffers::root::<test::test::UnionTest>(encoded).unwrap();
     println!("{:?}", decoded_test);
 }
 
@@ -71,6 +127,118 @@ fn decode_test() {
         builder.finished_data()
     };
     println!("{:x?}", encoded_test);
-    let decoded_test = UnionTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    let decoded_test = UnionTest::decode(&Decoder::new(encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+}
+
+fn build_union_test(set_a: bool) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let mut table_builder = test::test::Test2Builder::new(&mut builder);
+    table_builder.add_d(4);
+    table_builder.add_e(5);
+    table_builder.add_f(6);
+    let test = table_builder.finish().as_union_value();
+    let mut table_builder = test::test::UnionTestBuilder::new(&mut builder);
+    if set_a {
+        table_builder.add_a_type(test::test::TestUnion::B);
+        table_builder.add_a(test);
+    }
+    table_builder.add_b(3);
+    let table = table_builder.finish();
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn none_test() {
+    let encoded = encode(&UnionTest{a: TestUnion::NONE, b: 2});
+    let decoded_test = flatbuffers::root::<test::test::UnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::TestUnion::NONE);
+    assert!(decoded_test.a().is_none());
+    assert_eq!(decoded_test.b(), 2);
+
+    let decoded_test = UnionTest::decode(&Decoder::new(&encoded)).unwrap();
+    assert!(matches!(decoded_test.a, TestUnion::NONE));
+    assert_eq!(decoded_test.b, 2);
+
+    let decoded_test = UnionTest::decode(&Decoder::new(&build_union_test(false))).unwrap();
+    assert!(matches!(decoded_test.a, TestUnion::NONE));
+    assert_eq!(decoded_test.b, 3);
+}
+
+#[test]
+fn optional_test() {
+    let decoded_test = OptionalUnionTest::decode(&Decoder::new(&build_union_test(false))).unwrap();
+    assert!(decoded_test.a.is_none());
+    assert_eq!(decoded_test.b, 3);
+
+    let decoded_test = OptionalUnionTest::decode(&Decoder::new(&build_union_test(true))).unwrap();
+    assert!(matches!(decoded_test.a, Some(TestUnion::B(Test2{d: 4, e: 5, f: 6}))));
+    assert_eq!(decoded_test.b, 3);
+
+    let encoded = encode(&OptionalUnionTest{a: None, b: 2});
+    let decoded_test = flatbuffers::root::<test::test::UnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::TestUnion::NONE);
+    assert_eq!(decoded_test.b(), 2);
+    let decoded_test = OptionalUnionTest::decode(&Decoder::new(&encoded)).unwrap();
+    assert!(decoded_test.a.is_none());
+
+    // An explicit NONE is absent as well
+    let decoded_test = OptionalUnionTest::decode(&Decoder::new(&encode(&UnionTest{a: TestUnion::NONE, b: 2}))).unwrap();
+    assert!(decoded_test.a.is_none());
+}
+
+#[test]
+fn marked_none_test() {
+    let decoded_test = MarkedUnionTest::decode(&Decoder::new(&build_union_test(true))).unwrap();
+    assert!(matches!(decoded_test.a, MarkedUnion::B(Test2{d: 4, e: 5, f: 6})));
+
+    let decoded_test = MarkedUnionTest::decode(&Decoder::new(&build_union_test(false))).unwrap();
+    assert!(matches!(decoded_test.a, MarkedUnion::Empty));
+
+    let encoded = encode(&MarkedUnionTest{a: MarkedUnion::Empty, b: 2});
+    let decoded_test = flatbuffers::root::<test::test::UnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::TestUnion::NONE);
+    assert_eq!(decoded_test.b(), 2);
+}
+
+#[test]
+fn unknown_test() {
+    let encoded_test = build_union_test(true);
+    let result = UnionTest::decode(&Decoder::new(&encoded_test));
+    assert!(matches!(result.unwrap().a, TestUnion::B(_)));
+
+    let decoded_test = OpenUnionTest::decode(&Decoder::new(&encoded_test)).unwrap();
     println!("{:?}", decoded_test);
-}
\ No newline at end of file
+    let OpenTestUnion::Unknown(which_value, _) = decoded_test.a else {
+        panic!("Expected an unknown member");
+    };
+    assert_eq!(which_value, 2);
+    assert_eq!(decoded_test.b, 3);
+
+    // The raw member is written back unchanged
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    decoded_test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+    let decoded_test = flatbuffers::root::<test::test::UnionTest>(encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::TestUnion::B);
+    let a = decoded_test.a_as_b().unwrap();
+    assert_eq!((a.d(), a.e(), a.f()), (4, 5, 6));
+    assert_eq!(decoded_test.b(), 3);
+
+    let decoded_test = UnionTest::decode(&Decoder::new(encoded)).unwrap();
+    assert!(matches!(decoded_test.a, TestUnion::B(Test2{d: 4, e: 5, f: 6})));
+}
+
+#[test]
+fn explicit_id_test() {
+    let decoded_test = ReorderedUnionTest::decode(&Decoder::new(&build_union_test(true))).unwrap();
+    assert!(matches!(decoded_test.a, ReorderedUnion::B(Test2{d: 4, e: 5, f: 6})));
+
+    let encoded = encode(&ReorderedUnionTest{a: ReorderedUnion::A(Test{a: 1, b: 2, c: 3}), b: 2});
+    let decoded_test = flatbuffers::root::<test::test::UnionTest>(&encoded).unwrap();
+    assert_eq!(decoded_test.a_type(), test::test::TestUnion::A);
+    assert_eq!(decoded_test.a_as_a().unwrap().c(), 3);
+}
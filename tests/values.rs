@@ -0,0 +1,139 @@
+#![cfg(feature = "alloc")]
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::{NonZeroU32, Wrapping};
+use std::time::Duration;
+
+use femtoflatbuffers::{Decoder, DecodeError, Table};
+use femtoflatbuffers::table::Table;
+
+#[derive(Table, Debug, PartialEq)]
+struct ValueTest {
+    a: NonZeroU32,
+    b: Wrapping<i16>,
+    c: char,
+    d: u128,
+    e: i128,
+    f: Duration,
+    g: Ipv4Addr,
+    h: Ipv6Addr,
+    i: IpAddr,
+    j: Option<IpAddr>,
+    k: Vec<NonZeroU32>,
+    l: Vec<Duration>
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+struct RawValues {
+    a: u32,
+    c: u32,
+    nanos: u32,
+    i: &'static [u8]
+}
+
+const VALID_VALUES: RawValues = RawValues{a: 7, c: 'é' as u32, nanos: 999_999_999, i: &[10, 0, 0, 1]};
+
+fn build_value_test(values: &RawValues) -> Vec<u8> {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    // Keep zeroes in the buffer so they reach the decoder
+    builder.force_defaults(true);
+    let i = builder.create_vector(values.i);
+    let j = builder.create_vector(&Ipv6Addr::LOCALHOST.octets());
+    let k = builder.create_vector(&[1u32, 2, 3]);
+    let l = builder.create_vector(&[test::test::Duration::new(1, 2), test::test::Duration::new(3, 4)]);
+    let table = test::test::ValueTest::create(&mut builder, &test::test::ValueTestArgs{
+        a: values.a,
+        b: -5,
+        c: values.c,
+        d: Some(&test::test::U128::new(1, 2)),
+        e: Some(&test::test::U128::new(u64::MAX - 1, u64::MAX)),
+        f: Some(&test::test::Duration::new(90, values.nanos)),
+        g: Some(&test::test::Ipv4::new(&[192, 168, 0, 1])),
+        h: Some(&test::test::Ipv6::new(&[0xfe, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1])),
+        i: Some(i),
+        j: Some(j),
+        k: Some(k),
+        l: Some(l)
+    });
+    builder.finish(table, None);
+    builder.finished_data().to_vec()
+}
+
+#[test]
+fn encode_test() {
+    let test = ValueTest{
+        a: NonZeroU32::new(7).unwrap(),
+        b: Wrapping(-5),
+        c: 'é',
+        d: (2 << 64) | 1,
+        e: -2,
+        f: Duration::new(90, 999_999_999),
+        g: Ipv4Addr::new(192, 168, 0, 1),
+        h: "fe80::1".parse().unwrap(),
+        i: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        j: Some(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+        k: vec![NonZeroU32::new(1).unwrap(), NonZeroU32::new(2).unwrap(), NonZeroU32::new(3).unwrap()],
+        l: vec![Duration::new(1, 2), Duration::new(3, 4)]
+    };
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::ValueTest>(encoded).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a(), 7);
+    assert_eq!(decoded_test.b(), -5);
+    assert_eq!(decoded_test.c(), 'é' as u32);
+    assert_eq!((decoded_test.d().unwrap().lo(), decoded_test.d().unwrap().hi()), (1, 2));
+    assert_eq!((decoded_test.e().unwrap().lo(), decoded_test.e().unwrap().hi()), (u64::MAX - 1, u64::MAX));
+    assert_eq!((decoded_test.f().unwrap().secs(), decoded_test.f().unwrap().nanos()), (90, 999_999_999));
+    assert_eq!(decoded_test.g().unwrap().octets().iter().collect::<Vec<_>>(), vec![192, 168, 0, 1]);
+    assert_eq!(decoded_test.h().unwrap().octets().get(15), 1);
+    assert_eq!(decoded_test.i().unwrap().bytes(), &[10, 0, 0, 1]);
+    assert_eq!(decoded_test.j().unwrap().bytes(), &Ipv6Addr::LOCALHOST.octets());
+    assert_eq!(decoded_test.k().unwrap().iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    let l = decoded_test.l().unwrap();
+    assert_eq!((l.get(1).secs(), l.get(1).nanos()), (3, 4));
+
+    let decoded_test = ValueTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}
+
+#[test]
+fn decode_test() {
+    let encoded_test = build_value_test(&VALID_VALUES);
+    let decoded_test = ValueTest::decode(&Decoder::new(&encoded_test)).unwrap();
+    println!("{:?}", decoded_test);
+    assert_eq!(decoded_test.a.get(), 7);
+    assert_eq!(decoded_test.b, Wrapping(-5));
+    assert_eq!(decoded_test.c, 'é');
+    assert_eq!(decoded_test.d, (2 << 64) | 1);
+    assert_eq!(decoded_test.e, -2);
+    assert_eq!(decoded_test.f, Duration::new(90, 999_999_999));
+    assert_eq!(decoded_test.g, Ipv4Addr::new(192, 168, 0, 1));
+    assert_eq!(decoded_test.h, "fe80::1".parse::<Ipv6Addr>().unwrap());
+    assert_eq!(decoded_test.i, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+    assert_eq!(decoded_test.j, Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    assert_eq!(decoded_test.k.iter().map(|x| x.get()).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(decoded_test.l, vec![Duration::new(1, 2), Duration::new(3, 4)]);
+}
+
+#[test]
+fn decode_invalid_test() {
+    for values in [
+        RawValues{a: 0, ..VALID_VALUES},
+        RawValues{c: 0xd800, ..VALID_VALUES},
+        RawValues{nanos: 1_000_000_000, ..VALID_VALUES},
+        RawValues{i: &[10, 0, 0, 1, 0], ..VALID_VALUES}
+    ] {
+        let encoded_test = build_value_test(&values);
+        let result = ValueTest::decode(&Decoder::new(&encoded_test));
+        assert!(matches!(result, Err(DecodeError::InvalidValue)));
+    }
+}
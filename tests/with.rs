@@ -0,0 +1,173 @@
+use core::cmp::Ordering;
+
+use femtoflatbuffers::{Decoder, DecodeError, EncodeError, Table};
+use femtoflatbuffers::table::Table;
+
+// Thousandths, as an int
+mod fixed_point {
+    use femtoflatbuffers::{DecodeError, EncodeError};
+
+    pub fn encode(value: &f32) -> Result<i32, EncodeError> {
+        let value = (value * 1000.0).round();
+        if value < i32::MIN as f32 || value > i32::MAX as f32 {
+            return Err(EncodeError::InvalidValue);
+        }
+        Ok(value as i32)
+    }
+
+    pub fn decode(value: i32) -> Result<f32, DecodeError> {
+        Ok(value as f32 / 1000.0)
+    }
+}
+
+// A type from another crate, so it can't implement the traits itself
+mod ordering {
+    use core::cmp::Ordering;
+    use femtoflatbuffers::{DecodeError, EncodeError};
+
+    pub fn encode(value: &Ordering) -> Result<i8, EncodeError> {
+        Ok(*value as i8)
+    }
+
+    pub fn decode(value: i8) -> Result<Ordering, DecodeError> {
+        match value {
+            -1 => Ok(Ordering::Less),
+            0 => Ok(Ordering::Equal),
+            1 => Ok(Ordering::Greater),
+            _ => Err(DecodeError::InvalidValue)
+        }
+    }
+}
+
+// Writes the address as a string, and reads it back without copying the string first
+#[cfg(feature = "heapless")]
+mod dotted {
+    use core::net::Ipv4Addr;
+    use femtoflatbuffers::{DecodeError, EncodeError};
+
+    pub fn encode(value: &Ipv4Addr) -> Result<heapless::String<15>, EncodeError> {
+        let mut result = heapless::String::new();
+        core::fmt::write(&mut result, format_args!("{}", value)).map_err(|_| EncodeError::InvalidValue)?;
+        Ok(result)
+    }
+
+    pub fn decode(value: &str) -> Result<Ipv4Addr, DecodeError> {
+        value.parse().map_err(|_| DecodeError::InvalidValue)
+    }
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct Test {
+    a: i32,
+    b: i32,
+    c: i32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct WithTest {
+    #[femto(with = "fixed_point")]
+    a: f32,
+    b: i32,
+    #[femto(with = "fixed_point")]
+    c: f32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct RawOrderingTest {
+    a: i32,
+    b: i8,
+    c: i32
+}
+
+#[derive(Table, Debug, PartialEq)]
+struct OrderingTest {
+    a: i32,
+    #[femto(with = "ordering")]
+    b: Ordering,
+    c: i32
+}
+
+#[cfg(feature = "heapless")]
+#[derive(Table, Debug, PartialEq)]
+struct AddressTest {
+    #[femto(with = "dotted")]
+    a: core::net::Ipv4Addr,
+    b: u32
+}
+
+#[allow(dead_code, unused_imports)]
+#[path = "test_generated.rs"]
+mod test;
+
+#[test]
+fn encode_test() {
+    let test = WithTest{a: 1.5, b: 7, c: -0.125};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    println!("{:x?}", encoded);
+
+    let decoded_test = flatbuffers::root::<test::test::Test>(encoded).unwrap();
+    assert_eq!((decoded_test.a(), decoded_test.b(), decoded_test.c()), (1500, 7, -125));
+
+    // Laid out exactly like the plain table
+    let mut expected_buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut expected_buffer);
+    Test{a: 1500, b: 7, c: -125}.encode(&mut encoder).unwrap();
+    assert_eq!(encoded, encoder.done());
+
+    let decoded_test = WithTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+
+    let test = WithTest{a: 1.0e10, b: 7, c: 0.0};
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    assert!(matches!(test.encode(&mut encoder), Err(EncodeError::InvalidValue)));
+}
+
+#[test]
+fn decode_test() {
+    let mut builder = flatbuffers::FlatBufferBuilder::new();
+    let table = test::test::ScalarTest::create(&mut builder, &test::test::ScalarTestArgs{a: true, b: -1, c: 0.5, d: 0.25});
+    builder.finish(table, None);
+    let encoded_test = builder.finished_data();
+
+    // Shares the first two fields with ScalarTest
+    #[derive(Table, Debug, PartialEq)]
+    struct OrderingScalarTest {
+        a: bool,
+        #[femto(with = "ordering")]
+        b: Ordering
+    }
+    let decoded_test = OrderingScalarTest::decode(&Decoder::new(encoded_test)).unwrap();
+    assert_eq!(decoded_test, OrderingScalarTest{a: true, b: Ordering::Less});
+
+    let test = OrderingTest{a: 1, b: Ordering::Greater, c: 3};
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    assert_eq!(OrderingTest::decode(&Decoder::new(encoder.done())).unwrap(), test);
+
+    // The codec's own errors come straight through
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    RawOrderingTest{a: 1, b: 2, c: 3}.encode(&mut encoder).unwrap();
+    let result = OrderingTest::decode(&Decoder::new(encoder.done()));
+    assert!(matches!(result, Err(DecodeError::InvalidValue)));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn borrowed_wire_type_test() {
+    let test = AddressTest{a: core::net::Ipv4Addr::new(192, 168, 0, 1), b: 2};
+
+    let mut buffer = [0u8; 1024];
+    let mut encoder = femtoflatbuffers::Encoder::new(&mut buffer);
+    test.encode(&mut encoder).unwrap();
+    let encoded = encoder.done();
+    let decoded_test = flatbuffers::root::<test::test::StringTest>(encoded).unwrap();
+    assert_eq!(decoded_test.a(), Some("192.168.0.1"));
+
+    let decoded_test = AddressTest::decode(&Decoder::new(encoded)).unwrap();
+    assert_eq!(decoded_test, test);
+}